@@ -1,70 +1,72 @@
 // Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
 
-use serde::Deserialize;
+use resume::{codegen, contributions, css, html, json_resume, typst, Contributions, Edition, Error, Resume, SCHEMA_PATH};
 use std::env;
 use std::fs;
-use std::io::Write;
 use std::path::Path;
-use std::process::Command;
+use std::process::{self, Command};
 
-#[derive(Deserialize)]
-struct Resume {
-    profile: Profile,
-    skills: Skills,
-    experience: Vec<Experience>,
-    contacts: Vec<Contact>,
-}
+const PDF_DIR: &str = "assets/pdf";
+const JSON_RESUME_PATH: &str = "assets/resume.json";
+const THEME_CSS_PATH: &str = "assets/theme.css";
+const ASSETS_DIR: &str = "assets";
 
-#[derive(Deserialize)]
-struct Profile {
-    name: String,
-    title: String,
-    summary: String,
-}
+fn main() {
+    println!("cargo:rerun-if-changed=.git/HEAD");
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
 
-#[derive(Deserialize)]
-struct Skills {
-    cloud: Vec<String>,
-    devops: Vec<String>,
-    monitoring: Vec<String>,
-    languages: Vec<String>,
-    rust: Vec<String>,
-    databases: Vec<String>,
-    security: Vec<String>,
+    if let Err(e) = run() {
+        if let Error::Invalid { diagnostics, .. } = &e {
+            for diagnostic in diagnostics {
+                println!("cargo:warning={}", diagnostic);
+            }
+        }
+        eprintln!("{}", e);
+        process::exit(1);
+    }
 }
 
-#[derive(Deserialize)]
-struct Experience {
-    company: String,
-    position: String,
-    period: String,
-    location: String,
-    highlights: Vec<String>,
-}
+fn run() -> Result<(), Error> {
+    write_if_changed(Path::new(SCHEMA_PATH), &resume::schema()?)?;
+
+    // Newly added translations are picked up once an existing source changes
+    // or the build is forced. With runtime data the page reads content from
+    // `resumes.json`, so edits leave the wasm alone; the compiled-in copy,
+    // the PDFs and the other assets catch up on the next forced build.
+    let runtime_data = env::var_os("CARGO_FEATURE_RUNTIME_DATA").is_some();
+    let mut locales: Vec<(String, Resume)> = Vec::new();
+    for source in resume::locale_sources(Path::new("."))? {
+        if !runtime_data {
+            println!("cargo:rerun-if-changed={}", source.file);
+        }
+        let loaded = resume::load_file(Path::new(&source.file))?;
+        for warning in &loaded.warnings {
+            println!("cargo:warning={}", warning);
+        }
+        locales.push((source.locale, loaded.resume));
+    }
+    let editions = resume::editions(&locales);
 
-#[derive(Deserialize)]
-struct Contact {
-    platform: String,
-    url: String,
-    label: String,
-}
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR not set");
+    fs::create_dir_all(PDF_DIR).map_err(|e| Error::io(PDF_DIR, e))?;
+    write_if_changed(&Path::new(&out_dir).join("resume_data.rs"), &codegen::rust_code(&editions)?)?;
+    generate_build_info(&out_dir)?;
+    embed_snapshot(&out_dir, editions[0].resume.contributions.as_ref())?;
+    write_if_changed(&Path::new(&out_dir).join("head.html"), &html::head(&editions[0])?)?;
 
-fn main() {
-    println!("cargo:rerun-if-changed=resume.yaml");
-    println!("cargo:rerun-if-changed=.git/HEAD");
+    let theme = editions[0].resume.theme.clone().unwrap_or_default();
+    write_if_changed(Path::new(THEME_CSS_PATH), &css::theme(&theme)?)?;
 
-    let yaml_content = fs::read_to_string("resume.yaml").expect("Failed to read resume.yaml");
-    let resume: Resume = serde_yaml::from_str(&yaml_content).expect("Failed to parse resume.yaml");
+    let version = env::var("CARGO_PKG_VERSION").unwrap_or_else(|_| "unknown".to_string());
+    write_if_changed(Path::new(JSON_RESUME_PATH), &json_resume::export(&editions[0], &version)?)?;
 
-    generate_rust_code(&resume);
-    generate_build_info();
-    generate_pdf(&resume);
+    for edition in &editions {
+        generate_pdf(edition)?;
+    }
+    generate_card(&editions[0])
 }
 
-fn generate_build_info() {
-    let out_dir = env::var("OUT_DIR").expect("OUT_DIR not set");
-    let dest_path = Path::new(&out_dir).join("build_info.rs");
-
+fn generate_build_info(out_dir: &str) -> Result<(), Error> {
     let version = env::var("CARGO_PKG_VERSION").unwrap_or_else(|_| "unknown".to_string());
 
     let commit = Command::new("git")
@@ -82,185 +84,53 @@ fn generate_build_info() {
         version, commit, commit
     );
 
-    let existing = fs::read_to_string(&dest_path).unwrap_or_default();
-    if existing != content {
-        fs::write(&dest_path, content).expect("Failed to write build_info.rs");
-    }
+    write_if_changed(&Path::new(out_dir).join("build_info.rs"), &content)
 }
 
-fn generate_rust_code(resume: &Resume) {
-    let out_dir = env::var("OUT_DIR").expect("OUT_DIR not set");
-    let dest_path = Path::new(&out_dir).join("resume_data.rs");
-    let mut file = fs::File::create(&dest_path).expect("Failed to create resume_data.rs");
-
-    writeln!(file, "pub struct Profile {{").ok();
-    writeln!(file, "    pub name: &'static str,").ok();
-    writeln!(file, "    pub title: &'static str,").ok();
-    writeln!(file, "    pub summary: &'static str,").ok();
-    writeln!(file, "}}").ok();
-    writeln!(file).ok();
-
-    writeln!(file, "pub struct Experience {{").ok();
-    writeln!(file, "    pub company: &'static str,").ok();
-    writeln!(file, "    pub position: &'static str,").ok();
-    writeln!(file, "    pub period: &'static str,").ok();
-    writeln!(file, "    pub location: &'static str,").ok();
-    writeln!(file, "    pub highlights: &'static [&'static str],").ok();
-    writeln!(file, "}}").ok();
-    writeln!(file).ok();
-
-    writeln!(file, "pub struct Contact {{").ok();
-    writeln!(file, "    pub platform: &'static str,").ok();
-    writeln!(file, "    pub url: &'static str,").ok();
-    writeln!(file, "    pub label: &'static str,").ok();
-    writeln!(file, "}}").ok();
-    writeln!(file).ok();
-
-    writeln!(file, "pub const PROFILE: Profile = Profile {{").ok();
-    writeln!(file, "    name: {:?},", resume.profile.name).ok();
-    writeln!(file, "    title: {:?},", resume.profile.title).ok();
-    writeln!(file, "    summary: {:?},", resume.profile.summary).ok();
-    writeln!(file, "}};").ok();
-    writeln!(file).ok();
-
-    write_skills_const(&mut file, "SKILLS_CLOUD", &resume.skills.cloud);
-    write_skills_const(&mut file, "SKILLS_DEVOPS", &resume.skills.devops);
-    write_skills_const(&mut file, "SKILLS_MONITORING", &resume.skills.monitoring);
-    write_skills_const(&mut file, "SKILLS_LANGUAGES", &resume.skills.languages);
-    write_skills_const(&mut file, "SKILLS_RUST", &resume.skills.rust);
-    write_skills_const(&mut file, "SKILLS_DB", &resume.skills.databases);
-    write_skills_const(&mut file, "SKILLS_SECURITY", &resume.skills.security);
-
-    writeln!(file, "pub const EXPERIENCE: &[Experience] = &[").ok();
-    for exp in &resume.experience {
-        writeln!(file, "    Experience {{").ok();
-        writeln!(file, "        company: {:?},", exp.company).ok();
-        writeln!(file, "        position: {:?},", exp.position).ok();
-        writeln!(file, "        period: {:?},", exp.period).ok();
-        writeln!(file, "        location: {:?},", exp.location).ok();
-        writeln!(file, "        highlights: &[").ok();
-        for h in &exp.highlights {
-            writeln!(file, "            {:?},", h).ok();
+/// Embeds the committed contributions snapshot, if any. Like translations, a
+/// newly added snapshot is picked up once another source changes.
+fn embed_snapshot(out_dir: &str, config: Option<&Contributions>) -> Result<(), Error> {
+    let path = Path::new(contributions::SNAPSHOT_PATH);
+    let snapshot = match config.filter(|_| path.exists()) {
+        Some(config) => {
+            println!("cargo:rerun-if-changed={}", path.display());
+            let source = fs::read_to_string(path).map_err(|e| Error::io(path, e))?;
+            for warning in contributions::check_snapshot(contributions::SNAPSHOT_PATH, &source, config)? {
+                println!("cargo:warning={}", warning);
+            }
+            Some(source)
         }
-        writeln!(file, "        ],").ok();
-        writeln!(file, "    }},").ok();
-    }
-    writeln!(file, "];").ok();
-    writeln!(file).ok();
+        None => None,
+    };
 
-    writeln!(file, "pub const CONTACTS: &[Contact] = &[").ok();
-    for contact in &resume.contacts {
-        writeln!(file, "    Contact {{").ok();
-        writeln!(file, "        platform: {:?},", contact.platform).ok();
-        writeln!(file, "        url: {:?},", contact.url).ok();
-        writeln!(file, "        label: {:?},", contact.label).ok();
-        writeln!(file, "    }},").ok();
-    }
-    writeln!(file, "];").ok();
+    let content = format!("pub const CONTRIBUTIONS_SNAPSHOT: Option<&str> = {:?};\n", snapshot);
+    write_if_changed(&Path::new(out_dir).join("contributions.rs"), &content)
 }
 
-fn write_skills_const(file: &mut fs::File, name: &str, skills: &[String]) {
-    write!(file, "pub const {}: &[&str] = &[", name).ok();
-    for (i, skill) in skills.iter().enumerate() {
-        if i > 0 {
-            write!(file, ", ").ok();
-        }
-        write!(file, "{:?}", skill).ok();
+fn generate_pdf(edition: &Edition) -> Result<(), Error> {
+    for font in edition.resume.pdf.iter().flat_map(|pdf| &pdf.fonts) {
+        println!("cargo:rerun-if-changed={}", font);
     }
-    writeln!(file, "];").ok();
+    let pdf_path = Path::new(PDF_DIR).join(edition.pdf_name());
+    let pdf = typst::pdf(edition, Path::new("."))?;
+    fs::write(&pdf_path, pdf).map_err(|e| Error::io(&pdf_path, e))?;
+    println!("cargo:warning=Generated {}", pdf_path.display());
+    Ok(())
 }
 
-fn generate_pdf(resume: &Resume) {
-    let out_dir = env::var("OUT_DIR").expect("OUT_DIR not set");
-    let typ_path = Path::new(&out_dir).join("resume.typ");
-    let pdf_path = Path::new("assets/resume.pdf");
-
-    let mut typ_content = String::new();
-
-    typ_content.push_str("#set page(margin: 1.5cm)\n");
-    typ_content.push_str("#set text(font: \"Liberation Sans\", size: 9pt)\n");
-    typ_content.push_str("#show link: it => underline(text(fill: rgb(\"#0066cc\"), it))\n\n");
-
-    typ_content.push_str(&format!(
-        "#align(center)[#text(size: 18pt, weight: \"bold\")[{}]]\n",
-        escape_typst(&resume.profile.name)
-    ));
-    typ_content.push_str(&format!(
-        "#align(center)[#text(size: 11pt)[{}]]\n\n",
-        escape_typst(&resume.profile.title)
-    ));
-    typ_content.push_str(&format!("{}\n\n", escape_typst(&resume.profile.summary)));
-
-    typ_content.push_str("#text(size: 12pt, weight: \"bold\")[CORE COMPETENCIES]\n\n");
-
-    let skills_sections = [
-        ("Cloud & Infrastructure", &resume.skills.cloud),
-        ("DevOps & Automation", &resume.skills.devops),
-        ("Monitoring & Observability", &resume.skills.monitoring),
-        ("Programming Languages", &resume.skills.languages),
-        ("Rust Ecosystem", &resume.skills.rust),
-        ("Databases & Messaging", &resume.skills.databases),
-        ("Security", &resume.skills.security),
-    ];
-
-    for (label, skills) in skills_sections {
-        typ_content.push_str(&format!(
-            "*{}:* {}\n\n",
-            label,
-            skills.iter().map(|s| escape_typst(s)).collect::<Vec<_>>().join(", ")
-        ));
-    }
-
-    typ_content.push_str("#text(size: 12pt, weight: \"bold\")[PROFESSIONAL EXPERIENCE]\n\n");
-
-    for exp in &resume.experience {
-        typ_content.push_str(&format!(
-            "#text(size: 10pt, weight: \"bold\")[{} — {}]\n",
-            escape_typst(&exp.company),
-            escape_typst(&exp.position)
-        ));
-        typ_content.push_str(&format!(
-            "#text(style: \"italic\")[{} | {}]\n\n",
-            escape_typst(&exp.period),
-            escape_typst(&exp.location)
-        ));
-        for highlight in &exp.highlights {
-            typ_content.push_str(&format!("- {}\n", escape_typst(highlight)));
-        }
-        typ_content.push_str("\n");
-    }
-
-    typ_content.push_str("#text(size: 12pt, weight: \"bold\")[CONTACT]\n\n");
-
-    for contact in &resume.contacts {
-        typ_content.push_str(&format!(
-            "{}: #link(\"{}\")[{}]\n\n",
-            escape_typst(&contact.platform),
-            &contact.url,
-            escape_typst(&contact.label)
-        ));
-    }
-
-    fs::write(&typ_path, &typ_content).expect("Failed to write resume.typ");
-
-    let status = Command::new("typst")
-        .args(["compile", typ_path.to_str().unwrap(), pdf_path.to_str().unwrap()])
-        .status()
-        .expect("Failed to run typst. Is typst-cli installed?");
-
-    if !status.success() {
-        panic!("typst compile failed");
-    }
-
-    println!("cargo:warning=Generated resume.pdf with typst");
+fn generate_card(edition: &Edition) -> Result<(), Error> {
+    let card_path = Path::new(ASSETS_DIR).join(html::CARD_IMAGE);
+    let card = typst::card(edition, Path::new("."))?;
+    fs::write(&card_path, card).map_err(|e| Error::io(&card_path, e))?;
+    println!("cargo:warning=Generated {}", card_path.display());
+    Ok(())
 }
 
-fn escape_typst(s: &str) -> String {
-    s.replace('\\', "\\\\")
-        .replace('#', "\\#")
-        .replace('$', "\\$")
-        .replace('@', "\\@")
-        .replace('[', "\\[")
-        .replace(']', "\\]")
-        .replace('"', "\\\"")
+/// Leaves unchanged files alone so their timestamps do not retrigger builds.
+fn write_if_changed(path: &Path, content: &str) -> Result<(), Error> {
+    let existing = fs::read_to_string(path).unwrap_or_default();
+    if existing != content {
+        fs::write(path, content).map_err(|e| Error::io(path, e))?;
+    }
+    Ok(())
 }
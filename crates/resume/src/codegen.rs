@@ -0,0 +1,415 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+//! Rust source exposing the resumes as `&'static` data to the website, and
+//! the same data as JSON for loading it at runtime.
+
+use crate::{contributions, years_of_experience, Edition, Error, Labels, PrState, Tagged, FULL_VARIANT};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+
+/// `Labels` fields the website needs; the rest only feed derived strings and the PDF.
+const WEB_LABELS: [&str; 17] = [
+    "language",
+    "competencies",
+    "experience",
+    "education",
+    "contributions",
+    "period",
+    "location",
+    "years_total",
+    "download",
+    "loading",
+    "no_contributions",
+    "contributions_failed",
+    "rate_limited",
+    "retry",
+    "theme_light",
+    "theme_dark",
+    "theme_system",
+];
+
+/// Rust types of the generated fields that need a leaking deserializer,
+/// named in `LEAK_MODULE`. Strings are spelled `Text`, an alias of
+/// `&'static str`, because serde would otherwise borrow them from the input.
+const STR: &str = "Text";
+const OPTION_STR: &str = "Option<Text>";
+const STRS: &str = "&'static [Text]";
+
+/// Generated types after `Labels`, with the Rust type of every field.
+const TYPES: [(&str, &[(&str, &str)]); 8] = [
+    ("Profile", &[("name", STR), ("title", STR), ("summary", STR)]),
+    ("SkillGroup", &[("label", STR), ("items", STRS)]),
+    (
+        "Experience",
+        &[
+            ("company", STR),
+            ("position", STR),
+            ("period", STR),
+            ("duration", STR),
+            ("location", OPTION_STR),
+            ("highlights", STRS),
+        ],
+    ),
+    (
+        "Education",
+        &[
+            ("institution", STR),
+            ("degree", STR),
+            ("field", OPTION_STR),
+            ("period", OPTION_STR),
+            ("location", OPTION_STR),
+            ("notes", STRS),
+        ],
+    ),
+    ("Contact", &[("platform", STR), ("url", STR), ("label", STR)]),
+    (
+        "Resume",
+        &[
+            ("locale", STR),
+            ("variant", STR),
+            ("pdf", STR),
+            ("labels", "Labels"),
+            ("profile", "Profile"),
+            ("skill_groups", "&'static [SkillGroup]"),
+            ("years_of_experience", "u32"),
+            ("experience", "&'static [Experience]"),
+            ("education", "&'static [Education]"),
+            ("contacts", "&'static [Contact]"),
+        ],
+    ),
+    ("ContributionSource", &[("provider", STR), ("site", STR), ("url", STR)]),
+    (
+        "ContributionSearch",
+        &[("sources", "&'static [ContributionSource]"), ("states", STRS), ("count", "u32"), ("sort", STR)],
+    ),
+];
+
+/// Deserializers turning the owned values of [`json`] into the `&'static`
+/// fields of the generated types. A website loading its data at runtime
+/// leaks it, which it does once per page load.
+const LEAK_MODULE: &str = r#"mod leak {
+    use serde::de::{Deserialize, DeserializeOwned, Deserializer};
+
+    pub fn string<'de, D: Deserializer<'de>>(d: D) -> Result<&'static str, D::Error> {
+        Ok(String::deserialize(d)?.leak())
+    }
+
+    pub fn option<'de, D: Deserializer<'de>>(d: D) -> Result<Option<&'static str>, D::Error> {
+        Ok(Option::<String>::deserialize(d)?.map(|s| &*s.leak()))
+    }
+
+    pub fn strings<'de, D: Deserializer<'de>>(d: D) -> Result<&'static [&'static str], D::Error> {
+        let strings: Vec<&'static str> = Vec::<String>::deserialize(d)?.into_iter().map(|s| &*s.leak()).collect();
+        Ok(strings.leak())
+    }
+
+    pub fn slice<'de, D: Deserializer<'de>, T: DeserializeOwned>(d: D) -> Result<&'static [T], D::Error> {
+        Ok(Vec::<T>::deserialize(d)?.leak())
+    }
+}
+"#;
+
+/// Type definitions followed by `RESUMES`, one entry per edition, and the
+/// `CONTRIBUTIONS` search shared by all of them, meant to be `include!`d by
+/// the website. The types also deserialize from [`json`].
+pub fn rust_code(editions: &[Edition]) -> Result<String, Error> {
+    let mut out = String::new();
+
+    writeln!(out, "pub type Text = &'static str;")?;
+    writeln!(out)?;
+    writeln!(out, "{}", LEAK_MODULE)?;
+    write_struct(&mut out, "Labels", &WEB_LABELS.map(|field| (field, STR)))?;
+    for (name, fields) in TYPES {
+        write_struct(&mut out, name, fields)?;
+    }
+
+    writeln!(out, "pub const DEFAULT_VARIANT: &str = {:?};", FULL_VARIANT)?;
+    writeln!(out)?;
+    write_contributions(&mut out, editions)?;
+
+    writeln!(out, "pub const RESUMES: &[Resume] = &[")?;
+    for edition in editions {
+        write_resume(&mut out, edition)?;
+    }
+    writeln!(out, "];")?;
+
+    Ok(out)
+}
+
+fn write_struct(out: &mut String, name: &str, fields: &[(&str, &str)]) -> fmt::Result {
+    writeln!(out, "#[derive(serde::Deserialize)]")?;
+    writeln!(out, "pub struct {} {{", name)?;
+    for &(field, ty) in fields {
+        let leak = match ty {
+            STR => Some("string"),
+            OPTION_STR => Some("option"),
+            STRS => Some("strings"),
+            _ if ty.starts_with("&'static [") => Some("slice"),
+            _ => None,
+        };
+        if let Some(leak) = leak {
+            writeln!(out, "    #[serde(deserialize_with = \"leak::{}\")]", leak)?;
+        }
+        writeln!(out, "    pub {}: {},", field, ty)?;
+    }
+    writeln!(out, "}}")?;
+    writeln!(out)
+}
+
+/// `RESUMES` as a JSON array, for a website that loads its data at runtime
+/// instead of compiling it in.
+pub fn json(editions: &[Edition]) -> Result<String, Error> {
+    let resumes: Vec<WebResume> = editions.iter().map(WebResume::new).collect();
+    Ok(serde_json::to_string_pretty(&resumes)? + "\n")
+}
+
+/// One `RESUMES` entry, field for field.
+#[derive(Serialize)]
+struct WebResume<'a> {
+    locale: &'a str,
+    variant: &'a str,
+    pdf: String,
+    labels: BTreeMap<&'static str, &'a str>,
+    profile: WebProfile<'a>,
+    skill_groups: Vec<WebSkillGroup<'a>>,
+    years_of_experience: u32,
+    experience: Vec<WebExperience<'a>>,
+    education: Vec<WebEducation<'a>>,
+    contacts: Vec<WebContact<'a>>,
+}
+
+#[derive(Serialize)]
+struct WebProfile<'a> {
+    name: &'a str,
+    title: &'a str,
+    summary: &'a str,
+}
+
+#[derive(Serialize)]
+struct WebSkillGroup<'a> {
+    label: &'a str,
+    items: Vec<&'a str>,
+}
+
+#[derive(Serialize)]
+struct WebExperience<'a> {
+    company: &'a str,
+    position: &'a str,
+    period: String,
+    duration: String,
+    location: Option<&'a str>,
+    highlights: Vec<&'a str>,
+}
+
+#[derive(Serialize)]
+struct WebEducation<'a> {
+    institution: &'a str,
+    degree: &'a str,
+    field: Option<&'a str>,
+    period: Option<&'a str>,
+    location: Option<&'a str>,
+    notes: Vec<&'a str>,
+}
+
+#[derive(Serialize)]
+struct WebContact<'a> {
+    platform: &'a str,
+    url: &'a str,
+    label: &'a str,
+}
+
+impl<'a> WebResume<'a> {
+    fn new(edition: &'a Edition) -> Self {
+        let resume = &edition.resume;
+        let labels = &resume.labels;
+        WebResume {
+            locale: &edition.locale,
+            variant: &edition.variant,
+            pdf: pdf_href(edition),
+            labels: WEB_LABELS.into_iter().zip(web_labels(labels)).map(|(field, value)| (field, value.as_str())).collect(),
+            profile: WebProfile {
+                name: &resume.profile.name,
+                title: &resume.profile.title,
+                summary: resume.profile.summary.text(),
+            },
+            skill_groups: resume
+                .skills
+                .iter()
+                .map(|group| WebSkillGroup { label: &group.label, items: group.items.iter().map(Tagged::text).collect() })
+                .collect(),
+            years_of_experience: years_of_experience(&resume.experience),
+            experience: resume
+                .experience
+                .iter()
+                .map(|exp| WebExperience {
+                    company: &exp.company,
+                    position: &exp.position,
+                    period: exp.period(labels),
+                    duration: exp.duration(labels),
+                    location: exp.location.as_deref(),
+                    highlights: exp.highlights.iter().map(Tagged::text).collect(),
+                })
+                .collect(),
+            education: resume
+                .education
+                .iter()
+                .map(|edu| WebEducation {
+                    institution: &edu.institution,
+                    degree: &edu.degree,
+                    field: edu.field.as_deref(),
+                    period: edu.period.as_deref(),
+                    location: edu.location.as_deref(),
+                    notes: edu.notes.iter().map(String::as_str).collect(),
+                })
+                .collect(),
+            contacts: resume
+                .contacts
+                .iter()
+                .map(|c| WebContact { platform: &c.platform, url: &c.url, label: &c.label })
+                .collect(),
+        }
+    }
+}
+
+/// Values of the `WEB_LABELS` fields, in the same order.
+fn web_labels(labels: &Labels) -> [&String; WEB_LABELS.len()] {
+    [
+        &labels.language,
+        &labels.competencies,
+        &labels.experience,
+        &labels.education,
+        &labels.contributions,
+        &labels.period,
+        &labels.location,
+        &labels.years_total,
+        &labels.download,
+        &labels.loading,
+        &labels.no_contributions,
+        &labels.contributions_failed,
+        &labels.rate_limited,
+        &labels.retry,
+        &labels.theme_light,
+        &labels.theme_dark,
+        &labels.theme_system,
+    ]
+}
+
+fn pdf_href(edition: &Edition) -> String {
+    format!("pdf/{}", edition.pdf_name())
+}
+
+fn write_resume(out: &mut String, edition: &Edition) -> fmt::Result {
+    let resume = &edition.resume;
+    let labels = &resume.labels;
+
+    writeln!(out, "    Resume {{")?;
+    writeln!(out, "        locale: {:?},", edition.locale)?;
+    writeln!(out, "        variant: {:?},", edition.variant)?;
+    writeln!(out, "        pdf: {:?},", pdf_href(edition))?;
+
+    writeln!(out, "        labels: Labels {{")?;
+    for (field, value) in WEB_LABELS.iter().zip(web_labels(labels)) {
+        writeln!(out, "            {}: {:?},", field, value)?;
+    }
+    writeln!(out, "        }},")?;
+
+    writeln!(out, "        profile: Profile {{")?;
+    writeln!(out, "            name: {:?},", resume.profile.name)?;
+    writeln!(out, "            title: {:?},", resume.profile.title)?;
+    writeln!(out, "            summary: {:?},", resume.profile.summary.text())?;
+    writeln!(out, "        }},")?;
+
+    writeln!(out, "        skill_groups: &[")?;
+    for group in &resume.skills {
+        writeln!(out, "            SkillGroup {{")?;
+        writeln!(out, "                label: {:?},", group.label)?;
+        write!(out, "                items: ")?;
+        write_str_slice(out, group.items.iter().map(Tagged::text))?;
+        writeln!(out, ",")?;
+        writeln!(out, "            }},")?;
+    }
+    writeln!(out, "        ],")?;
+
+    writeln!(out, "        years_of_experience: {},", years_of_experience(&resume.experience))?;
+
+    writeln!(out, "        experience: &[")?;
+    for exp in &resume.experience {
+        writeln!(out, "            Experience {{")?;
+        writeln!(out, "                company: {:?},", exp.company)?;
+        writeln!(out, "                position: {:?},", exp.position)?;
+        writeln!(out, "                period: {:?},", exp.period(labels))?;
+        writeln!(out, "                duration: {:?},", exp.duration(labels))?;
+        writeln!(out, "                location: {:?},", exp.location)?;
+        write!(out, "                highlights: ")?;
+        write_str_slice(out, exp.highlights.iter().map(Tagged::text))?;
+        writeln!(out, ",")?;
+        writeln!(out, "            }},")?;
+    }
+    writeln!(out, "        ],")?;
+
+    writeln!(out, "        education: &[")?;
+    for edu in &resume.education {
+        writeln!(out, "            Education {{")?;
+        writeln!(out, "                institution: {:?},", edu.institution)?;
+        writeln!(out, "                degree: {:?},", edu.degree)?;
+        writeln!(out, "                field: {:?},", edu.field)?;
+        writeln!(out, "                period: {:?},", edu.period)?;
+        writeln!(out, "                location: {:?},", edu.location)?;
+        write!(out, "                notes: ")?;
+        write_str_slice(out, edu.notes.iter().map(String::as_str))?;
+        writeln!(out, ",")?;
+        writeln!(out, "            }},")?;
+    }
+    writeln!(out, "        ],")?;
+
+    writeln!(out, "        contacts: &[")?;
+    for contact in &resume.contacts {
+        writeln!(out, "            Contact {{")?;
+        writeln!(out, "                platform: {:?},", contact.platform)?;
+        writeln!(out, "                url: {:?},", contact.url)?;
+        writeln!(out, "                label: {:?},", contact.label)?;
+        writeln!(out, "            }},")?;
+    }
+    writeln!(out, "        ],")?;
+    writeln!(out, "    }},")
+}
+
+/// `None` without a `contributions` block; the listed states are spelled
+/// out, every one when the block names none. Each request is a source.
+fn write_contributions(out: &mut String, editions: &[Edition]) -> fmt::Result {
+    let Some(config) = editions.first().and_then(|edition| edition.resume.contributions.as_ref()) else {
+        writeln!(out, "pub const CONTRIBUTIONS: Option<ContributionSearch> = None;")?;
+        return writeln!(out);
+    };
+    let states = if config.states.is_empty() { &PrState::ALL[..] } else { &config.states };
+
+    writeln!(out, "pub const CONTRIBUTIONS: Option<ContributionSearch> = Some(ContributionSearch {{")?;
+    writeln!(out, "    sources: &[")?;
+    for source in contributions::sources(config) {
+        writeln!(out, "        ContributionSource {{")?;
+        writeln!(out, "            provider: {:?},", source.provider)?;
+        writeln!(out, "            site: {:?},", source.site)?;
+        writeln!(out, "            url: {:?},", source.url)?;
+        writeln!(out, "        }},")?;
+    }
+    writeln!(out, "    ],")?;
+    write!(out, "    states: ")?;
+    write_str_slice(out, states.iter().map(|state| state.as_str()))?;
+    writeln!(out, ",")?;
+    writeln!(out, "    count: {},", config.count)?;
+    writeln!(out, "    sort: {:?},", config.sort.as_str())?;
+    writeln!(out, "}});")?;
+    writeln!(out)
+}
+
+fn write_str_slice<'a>(out: &mut String, items: impl Iterator<Item = &'a str>) -> fmt::Result {
+    write!(out, "&[")?;
+    for (i, item) in items.enumerate() {
+        if i > 0 {
+            write!(out, ", ")?;
+        }
+        write!(out, "{:?}", item)?;
+    }
+    write!(out, "]")
+}
@@ -0,0 +1,359 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+//! Requests behind the website's contributions section, compiled from the
+//! `contributions` block, and the snapshot of their results the website
+//! shows until live requests succeed.
+
+use crate::{Contributions, Diagnostic, Error, Forge, GitHub, PrSort, PrState, Provider, Severity};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use url::Url;
+
+/// Committed snapshot written by `resume contributions`; optional.
+pub const SNAPSHOT_PATH: &str = "contributions.json";
+
+const GITHUB_SITE: &str = "https://github.com";
+const GITHUB_SEARCH_API: &str = "https://api.github.com/search/issues";
+/// Largest pages the APIs return; Gitea's is configurable, this is the
+/// default.
+const GITHUB_MAX_PAGE: u32 = 100;
+const GITLAB_MAX_PAGE: u32 = 100;
+const GITEA_MAX_PAGE: u32 = 50;
+
+/// One API request of the contributions section.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Source {
+    /// `github`, `gitlab` or `gitea`, telling the website how to read the
+    /// response.
+    pub provider: &'static str,
+    /// Web address of the forge, without a trailing slash.
+    pub site: String,
+    pub url: String,
+}
+
+impl Provider {
+    pub fn id(&self) -> &'static str {
+        match self {
+            Provider::GitHub(_) => "github",
+            Provider::GitLab(_) => "gitlab",
+            Provider::Gitea(_) => "gitea",
+        }
+    }
+}
+
+/// Requests listing the pull requests of every provider: one GitHub
+/// search, one request per GitLab or Gitea project.
+///
+/// The APIs cannot narrow every combination of states, nor sort by
+/// comments everywhere, so the website filters, sorts and truncates the
+/// merged results; requests it cannot narrow ask for a full page.
+pub fn sources(config: &Contributions) -> Vec<Source> {
+    let mut sources = Vec::new();
+    for provider in &config.providers {
+        match provider {
+            Provider::GitHub(github) => sources.push(Source {
+                provider: provider.id(),
+                site: GITHUB_SITE.to_string(),
+                url: github_url(github, config),
+            }),
+            Provider::GitLab(forge) | Provider::Gitea(forge) => {
+                let site = forge.url.trim_end_matches('/');
+                for project in &forge.projects {
+                    let url = match provider {
+                        Provider::GitLab(_) => gitlab_url(site, project, forge, config),
+                        _ => gitea_url(site, project, forge, config),
+                    };
+                    sources.push(Source { provider: provider.id(), site: site.to_string(), url });
+                }
+            }
+        }
+    }
+    sources
+}
+
+fn github_url(github: &GitHub, config: &Contributions) -> String {
+    let mut query = vec![format!("author:{}", github.username), "type:pr".to_string(), "is:public".to_string()];
+    query.extend(github.orgs.iter().map(|org| format!("user:{}", org)));
+    query.extend(github.repos.iter().map(|repo| format!("repo:{}", repo)));
+    if !github.own_repos {
+        query.push(format!("-user:{}", github.username));
+    }
+    query.extend(github.exclude_orgs.iter().map(|org| format!("-user:{}", org)));
+    query.extend(github.exclude_repos.iter().map(|repo| format!("-repo:{}", repo)));
+
+    let states = github_states(&config.states);
+    query.extend(states.unwrap_or_default().iter().map(|q| q.to_string()));
+    let mut url = Url::parse(GITHUB_SEARCH_API).expect("search API address is valid");
+    url.query_pairs_mut()
+        .append_pair("q", &query.join(" "))
+        .append_pair("sort", config.sort.as_str())
+        .append_pair("order", "desc")
+        .append_pair("per_page", &page(states.is_some(), config, GITHUB_MAX_PAGE));
+    url.into()
+}
+
+/// Qualifiers matching exactly `states`, or `None` when none do. Open pull
+/// requests count as unmerged, so open and merged ones share no qualifier.
+fn github_states(states: &[PrState]) -> Option<&'static [&'static str]> {
+    match listed(states) {
+        (true, true, true) => Some(&[]),
+        (true, false, false) => Some(&["is:open"]),
+        (false, true, false) => Some(&["is:merged"]),
+        (false, false, true) => Some(&["is:closed", "is:unmerged"]),
+        (true, false, true) => Some(&["is:unmerged"]),
+        (false, true, true) => Some(&["is:closed"]),
+        (true, true, false) | (false, false, false) => None,
+    }
+}
+
+/// Merge requests of `forge.username` in `project`, whose path GitLab takes
+/// encoded as one segment.
+fn gitlab_url(site: &str, project: &str, forge: &Forge, config: &Contributions) -> String {
+    let state = match listed(&config.states) {
+        (true, false, false) => Some("opened"),
+        (false, true, false) => Some("merged"),
+        (false, false, true) => Some("closed"),
+        (true, true, true) => Some("all"),
+        _ => None,
+    };
+    let order_by = match config.sort {
+        PrSort::Updated => "updated_at",
+        PrSort::Created | PrSort::Comments => "created_at",
+    };
+    let exact = state.is_some() && config.sort != PrSort::Comments;
+
+    let address = format!("{}/api/v4/projects/{}/merge_requests", site, project.replace('/', "%2F"));
+    let mut url = Url::parse(&address).expect("validated forge address");
+    url.query_pairs_mut()
+        .append_pair("author_username", &forge.username)
+        .append_pair("state", state.unwrap_or("all"))
+        .append_pair("order_by", order_by)
+        .append_pair("sort", "desc")
+        .append_pair("per_page", &page(exact, config, GITLAB_MAX_PAGE));
+    url.into()
+}
+
+/// Pull requests of `forge.username` in `project`, newest first. Gitea
+/// knows open and closed; merged ones are closed.
+fn gitea_url(site: &str, project: &str, forge: &Forge, config: &Contributions) -> String {
+    let (open, merged, closed) = listed(&config.states);
+    let state = match (open, merged || closed) {
+        (true, false) => "open",
+        (false, true) => "closed",
+        _ => "all",
+    };
+    let exact = (open && merged && closed || state == "open") && config.sort == PrSort::Created;
+
+    let mut url = Url::parse(&format!("{}/api/v1/repos/{}/issues", site, project)).expect("validated forge address");
+    url.query_pairs_mut()
+        .append_pair("type", "pulls")
+        .append_pair("created_by", &forge.username)
+        .append_pair("state", state)
+        .append_pair("limit", &page(exact, config, GITEA_MAX_PAGE));
+    url.into()
+}
+
+/// Whether open, merged and closed pull requests are listed.
+fn listed(states: &[PrState]) -> (bool, bool, bool) {
+    let has = |state| states.is_empty() || states.contains(&state);
+    (has(PrState::Open), has(PrState::Merged), has(PrState::Closed))
+}
+
+/// Page size of a request: just what is shown when the API selects and
+/// orders exactly the listed pull requests, a full page otherwise.
+fn page(exact: bool, config: &Contributions, max: u32) -> String {
+    (if exact { config.count } else { max }).min(max).to_string()
+}
+
+/// Responses as the website reads them, reduced to the fields it uses.
+#[derive(Serialize, Deserialize)]
+struct Snapshot {
+    responses: Vec<SnapshotResponse>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SnapshotResponse {
+    url: String,
+    body: Value,
+}
+
+/// Fields of a pull request the website reads, by provider.
+fn used_fields(provider: &str) -> &'static [&'static str] {
+    match provider {
+        "github" => &["title", "html_url", "state", "created_at", "updated_at", "comments", "pull_request", "repository_url"],
+        "gitlab" => &["title", "web_url", "state", "created_at", "updated_at", "user_notes_count"],
+        _ => &["title", "html_url", "state", "created_at", "updated_at", "comments", "pull_request", "repository"],
+    }
+}
+
+/// Snapshot of the bodies the `sources` returned.
+pub fn snapshot(responses: &[(Source, String)]) -> Result<String, Error> {
+    let responses = responses
+        .iter()
+        .map(|(source, body)| Ok(SnapshotResponse { url: source.url.clone(), body: reduce(source, body)? }))
+        .collect::<Result<_, Error>>()?;
+    Ok(serde_json::to_string_pretty(&Snapshot { responses })? + "\n")
+}
+
+/// `body` with only the pull requests' used fields; GitHub wraps them in a
+/// search result, the others answer with a plain list.
+fn reduce(source: &Source, body: &str) -> Result<Value, Error> {
+    let unexpected = |reason: String| Error::Http(format!("{}: unexpected response: {}", source.url, reason));
+    let mut body: Value = serde_json::from_str(body).map_err(|e| unexpected(e.to_string()))?;
+    let items = if source.provider == "github" { body.get_mut("items").map(Value::take) } else { Some(body) };
+    let Some(Value::Array(mut items)) = items else {
+        return Err(unexpected("no list of pull requests".to_string()));
+    };
+
+    let fields = used_fields(source.provider);
+    for item in &mut items {
+        if let Value::Object(item) = item {
+            item.retain(|field, _| fields.contains(&field.as_str()));
+        }
+    }
+    Ok(if source.provider == "github" { serde_json::json!({ "items": items }) } else { Value::Array(items) })
+}
+
+/// Checks the snapshot `source`, read from `file`, before the website embeds
+/// it. Warns when its requests differ from those of `config`.
+pub fn check_snapshot(file: &str, source: &str, config: &Contributions) -> Result<Vec<Diagnostic>, Error> {
+    let diagnostic = |severity, line, column, message: String| Diagnostic {
+        severity,
+        file: file.to_string(),
+        line,
+        column,
+        message,
+    };
+    let snapshot: Snapshot = serde_json::from_str(source).map_err(|e| {
+        let message = e.to_string();
+        let message = message.split(" at line ").next().unwrap_or(&message).to_string();
+        let diagnostics = vec![diagnostic(Severity::Error, e.line(), e.column(), message)];
+        Error::Invalid { file: file.to_string(), diagnostics }
+    })?;
+
+    let mut expected: Vec<String> = sources(config).into_iter().map(|source| source.url).collect();
+    let mut taken: Vec<String> = snapshot.responses.into_iter().map(|response| response.url).collect();
+    expected.sort();
+    taken.sort();
+    if expected == taken {
+        return Ok(Vec::new());
+    }
+    let message = "taken for other requests than the `contributions` block makes; refresh it with `resume contributions`";
+    Ok(vec![diagnostic(Severity::Warning, 1, 1, message.to_string())])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn github() -> GitHub {
+        GitHub {
+            username: "jane".to_string(),
+            orgs: Vec::new(),
+            exclude_orgs: Vec::new(),
+            repos: Vec::new(),
+            exclude_repos: Vec::new(),
+            own_repos: false,
+        }
+    }
+
+    fn forge(url: &str, project: &str) -> Forge {
+        Forge { url: url.to_string(), username: "jane.doe".to_string(), projects: vec![project.to_string()] }
+    }
+
+    fn config(providers: Vec<Provider>) -> Contributions {
+        Contributions { providers, states: Vec::new(), count: 5, sort: PrSort::Created }
+    }
+
+    fn url(config: &Contributions) -> String {
+        sources(config).remove(0).url
+    }
+
+    fn query(url: &str) -> String {
+        let url = Url::parse(url).unwrap();
+        url.query_pairs().find(|(key, _)| key == "q").unwrap().1.into_owned()
+    }
+
+    #[test]
+    fn searches_foreign_repos_by_default() {
+        let url = url(&config(vec![Provider::GitHub(github())]));
+        assert_eq!(query(&url), "author:jane type:pr is:public -user:jane");
+        assert!(url.ends_with("&sort=created&order=desc&per_page=5"));
+    }
+
+    #[test]
+    fn compiles_filters() {
+        let github = GitHub {
+            orgs: vec!["rust-lang".to_string()],
+            exclude_repos: vec!["rust-lang/rust".to_string()],
+            own_repos: true,
+            ..github()
+        };
+        let config = Contributions { states: vec![PrState::Merged], sort: PrSort::Updated, ..config(vec![Provider::GitHub(github)]) };
+        let url = url(&config);
+        assert_eq!(query(&url), "author:jane type:pr is:public user:rust-lang -repo:rust-lang/rust is:merged");
+        assert!(url.contains("&sort=updated&"));
+    }
+
+    #[test]
+    fn widens_page_for_inexpressible_states() {
+        let config = Contributions { states: vec![PrState::Open, PrState::Merged], ..config(vec![Provider::GitHub(github())]) };
+        let url = url(&config);
+        assert_eq!(query(&url), "author:jane type:pr is:public -user:jane");
+        assert!(url.ends_with("&per_page=100"));
+    }
+
+    #[test]
+    fn requests_each_forge_project() {
+        let gitlab = Forge { projects: vec!["group/sub/app".to_string(), "group/lib".to_string()], ..forge("https://gitlab.com/", "") };
+        let config = config(vec![Provider::GitLab(gitlab), Provider::Gitea(forge("https://codeberg.org", "forgejo/forgejo"))]);
+        let sources = sources(&config);
+        assert_eq!(sources.len(), 3);
+        assert_eq!((sources[0].provider, sources[0].site.as_str()), ("gitlab", "https://gitlab.com"));
+        assert_eq!(
+            sources[0].url,
+            "https://gitlab.com/api/v4/projects/group%2Fsub%2Fapp/merge_requests\
+             ?author_username=jane.doe&state=all&order_by=created_at&sort=desc&per_page=5"
+        );
+        assert_eq!(
+            sources[2].url,
+            "https://codeberg.org/api/v1/repos/forgejo/forgejo/issues?type=pulls&created_by=jane.doe&state=all&limit=5"
+        );
+
+        let merged = Contributions { states: vec![PrState::Merged], ..config };
+        let sources = super::sources(&merged);
+        assert!(sources[0].url.contains("&state=merged&") && sources[0].url.ends_with("&per_page=5"));
+        assert!(sources[2].url.ends_with("&state=closed&limit=50"));
+    }
+
+    #[test]
+    fn snapshots_used_fields() {
+        let response = r#"{"total_count": 1, "items": [{
+            "title": "Fix typo", "html_url": "https://github.com/acme/app/pull/1", "state": "closed",
+            "created_at": "2025-01-02T03:04:05Z", "pull_request": {"merged_at": "2025-01-03T00:00:00Z"},
+            "repository_url": "https://api.github.com/repos/acme/app", "user": {"login": "jane"}
+        }]}"#;
+        let config = config(vec![Provider::GitHub(github())]);
+        let source = sources(&config).remove(0);
+        let snapshot = snapshot(&[(source, response.to_string())]).unwrap();
+        assert!(!snapshot.contains("total_count") && !snapshot.contains("login"));
+        assert!(snapshot.contains("\"merged_at\""));
+        assert!(check_snapshot("contributions.json", &snapshot, &config).unwrap().is_empty());
+
+        let other = Contributions { count: 3, ..config };
+        let warnings = check_snapshot("contributions.json", &snapshot, &other).unwrap();
+        assert_eq!(warnings.len(), 1);
+    }
+
+    #[test]
+    fn rejects_unexpected_responses() {
+        let config = config(vec![Provider::Gitea(forge("https://codeberg.org", "a/b"))]);
+        let source = sources(&config).remove(0);
+        assert!(matches!(snapshot(&[(source, r#"{"message": "not found"}"#.to_string())]), Err(Error::Http(_))));
+
+        let Err(Error::Invalid { diagnostics, .. }) = check_snapshot("contributions.json", "{\"responses\": 1}", &config) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+    }
+}
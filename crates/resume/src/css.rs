@@ -0,0 +1,100 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+//! Custom properties of the `theme` block, loaded by the website ahead of
+//! `styles/main.css`.
+
+use crate::{Error, Palette, Theme};
+use std::fmt::{self, Write};
+
+/// Font names CSS reads as keywords, which must stay unquoted and mean
+/// nothing to Typst.
+const GENERIC_FAMILIES: [&str; 13] = [
+    "serif",
+    "sans-serif",
+    "monospace",
+    "cursive",
+    "fantasy",
+    "system-ui",
+    "ui-serif",
+    "ui-sans-serif",
+    "ui-monospace",
+    "ui-rounded",
+    "math",
+    "-apple-system",
+    "BlinkMacSystemFont",
+];
+
+/// Colour and font variables `styles/main.css` uses. The dark palette is the
+/// base; `data-theme="light"` on `<html>` selects the light one, which also
+/// applies when printing and, before the theme switcher sets the attribute,
+/// when the system prefers it.
+pub fn theme(theme: &Theme) -> Result<String, Error> {
+    let mut out = String::new();
+    writeln!(out, "/* Generated from the `theme` block of resume.yaml; do not edit. */")?;
+    writeln!(out, ":root {{")?;
+    writeln!(out, "    --font: {};", font_stack(&theme.font))?;
+    writeln!(out, "    --mono: {};", font_stack(&theme.mono))?;
+    write_palette(&mut out, "dark", &theme.dark, "    ")?;
+    writeln!(out, "}}")?;
+    writeln!(out)?;
+    writeln!(out, ":root[data-theme=\"light\"] {{")?;
+    write_palette(&mut out, "light", &theme.light, "    ")?;
+    writeln!(out, "}}")?;
+    writeln!(out)?;
+    writeln!(out, "@media (prefers-color-scheme: light) {{")?;
+    writeln!(out, "    :root:not([data-theme]) {{")?;
+    write_palette(&mut out, "light", &theme.light, "        ")?;
+    writeln!(out, "    }}")?;
+    writeln!(out, "}}")?;
+    writeln!(out)?;
+    writeln!(out, "@media print {{")?;
+    writeln!(out, "    :root {{")?;
+    write_palette(&mut out, "light", &theme.light, "        ")?;
+    writeln!(out, "    }}")?;
+    writeln!(out, "}}")?;
+    Ok(out)
+}
+
+fn write_palette(out: &mut String, scheme: &str, palette: &Palette, indent: &str) -> fmt::Result {
+    writeln!(out, "{}color-scheme: {};", indent, scheme)?;
+    for (name, colour) in palette.colours() {
+        writeln!(out, "{}--{}: {};", indent, variable(name), colour)?;
+    }
+    Ok(())
+}
+
+/// Custom property of a palette colour, named as `styles/main.css` has
+/// always called it.
+fn variable(colour: &str) -> &str {
+    match colour {
+        "background" => "bg",
+        "surface" => "bg-card",
+        "muted" => "text-secondary",
+        "success" => "green",
+        other => other,
+    }
+}
+
+pub(crate) fn is_generic_family(family: &str) -> bool {
+    GENERIC_FAMILIES.contains(&family)
+}
+
+/// `font-family` value with every named family quoted.
+fn font_stack(families: &[String]) -> String {
+    let families: Vec<String> = families
+        .iter()
+        .map(|family| if is_generic_family(family) { family.clone() } else { format!("\"{}\"", family) })
+        .collect();
+    families.join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quotes_named_families() {
+        let families = ["-apple-system", "Segoe UI", "sans-serif"].map(String::from);
+        assert_eq!(font_stack(&families), "-apple-system, \"Segoe UI\", sans-serif");
+    }
+}
@@ -0,0 +1,248 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+use crate::validate::is_valid_period;
+use crate::{Error, Labels};
+use std::collections::HashMap;
+use std::fmt;
+use yaml_rust2::parser::{Event, MarkedEventReceiver, Parser};
+use yaml_rust2::scanner::Marker;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+/// Validation finding pointing at a position in a resume source.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub file: String,
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    /// `file:line:column: error: message`, the format editors and CI annotate.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let label = match self.severity {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        write!(f, "{}:{}:{}: {}: {}", self.file, self.line, self.column, label, self.message)
+    }
+}
+
+/// Collects findings while a source is loaded and validated.
+pub(crate) struct Diagnostics<'a> {
+    file: &'a str,
+    spans: SpanIndex,
+    /// Turns model paths into paths of the source format.
+    pub(crate) map_path: fn(&str) -> String,
+    found: Vec<Diagnostic>,
+}
+
+impl<'a> Diagnostics<'a> {
+    pub(crate) fn new(file: &'a str, source: &str) -> Self {
+        Diagnostics {
+            file,
+            spans: SpanIndex::build(source),
+            map_path: str::to_string,
+            found: Vec::new(),
+        }
+    }
+
+    pub(crate) fn emit(&mut self, severity: Severity, line: usize, column: usize, message: &str) {
+        self.found.push(Diagnostic {
+            severity,
+            file: self.file.to_string(),
+            line,
+            column,
+            message: message.to_string(),
+        });
+    }
+
+    fn report(&mut self, severity: Severity, path: &str, message: impl AsRef<str>) {
+        let path = (self.map_path)(path);
+        let (line, column) = self.spans.locate(&path);
+        self.emit(severity, line, column, &format!("{}: {}", path, message.as_ref()));
+    }
+
+    pub(crate) fn warn(&mut self, path: &str, message: impl AsRef<str>) {
+        self.report(Severity::Warning, path, message);
+    }
+
+    pub(crate) fn error(&mut self, path: &str, message: impl AsRef<str>) {
+        self.report(Severity::Error, path, message);
+    }
+
+    pub(crate) fn require(&mut self, path: &str, value: &str) {
+        if value.trim().is_empty() {
+            self.error(path, "must not be empty");
+        }
+    }
+
+    pub(crate) fn check_period(&mut self, path: &str, period: &str, labels: &Labels) {
+        if !is_valid_period(period, labels) {
+            self.error(path, format!("invalid period `{}`, expected e.g. `2008 — 2013` or `Sep 2019 — Present`", period));
+        }
+    }
+
+    pub(crate) fn has_errors(&self) -> bool {
+        self.found.iter().any(|d| d.severity == Severity::Error)
+    }
+
+    /// The warnings, or every finding when at least one of them is an error.
+    pub(crate) fn finish(self) -> Result<Vec<Diagnostic>, Error> {
+        if self.has_errors() {
+            Err(Error::Invalid { file: self.file.to_string(), diagnostics: self.found })
+        } else {
+            Ok(self.found)
+        }
+    }
+}
+
+/// Maps dotted field paths such as `contacts[1].url` to the line and column
+/// where their value starts, so semantic checks can point into the source.
+#[derive(Default)]
+struct SpanIndex {
+    spans: HashMap<String, (usize, usize)>,
+    stack: Vec<Node>,
+}
+
+enum Node {
+    Map { path: String, key: Option<String> },
+    Seq { path: String, index: usize },
+}
+
+impl SpanIndex {
+    fn build(source: &str) -> Self {
+        let mut index = SpanIndex::default();
+        // Syntax errors are reported by the deserializer with their own location.
+        let _ = Parser::new_from_str(source).load(&mut index, false);
+        index
+    }
+
+    /// Falls back to the closest located ancestor, e.g. the entry that is
+    /// missing an optional key.
+    fn locate(&self, path: &str) -> (usize, usize) {
+        let mut path = path;
+        loop {
+            if let Some(&span) = self.spans.get(path) {
+                return span;
+            }
+            match path.rfind(['.', '[']) {
+                Some(i) => path = &path[..i],
+                None => return (1, 1),
+            }
+        }
+    }
+
+    /// Path of the value the next event belongs to, or `None` when the next
+    /// scalar is a mapping key.
+    fn value_path(&self) -> Option<String> {
+        match self.stack.last() {
+            None => Some(String::new()),
+            Some(Node::Map { key: None, .. }) => None,
+            Some(Node::Map { path, key: Some(key) }) if path.is_empty() => Some(key.clone()),
+            Some(Node::Map { path, key: Some(key) }) => Some(format!("{}.{}", path, key)),
+            Some(Node::Seq { path, index }) => Some(format!("{}[{}]", path, index)),
+        }
+    }
+
+    fn end_value(&mut self) {
+        match self.stack.last_mut() {
+            Some(Node::Map { key, .. }) => *key = None,
+            Some(Node::Seq { index, .. }) => *index += 1,
+            None => {}
+        }
+    }
+}
+
+impl MarkedEventReceiver for SpanIndex {
+    fn on_event(&mut self, ev: Event, mark: Marker) {
+        let span = (mark.line(), mark.col() + 1);
+        match ev {
+            Event::Scalar(value, ..) => match self.value_path() {
+                Some(path) => {
+                    self.spans.insert(path, span);
+                    self.end_value();
+                }
+                None => {
+                    if let Some(Node::Map { key, .. }) = self.stack.last_mut() {
+                        *key = Some(value);
+                    }
+                    if let Some(path) = self.value_path() {
+                        self.spans.insert(path, span);
+                    }
+                }
+            },
+            Event::Alias(_) => {
+                if let Some(path) = self.value_path() {
+                    self.spans.insert(path, span);
+                }
+                self.end_value();
+            }
+            Event::MappingStart(..) | Event::SequenceStart(..) => {
+                let path = self.value_path().unwrap_or_default();
+                self.spans.insert(path.clone(), span);
+                self.stack.push(match ev {
+                    Event::MappingStart(..) => Node::Map { path, key: None },
+                    _ => Node::Seq { path, index: 0 },
+                });
+            }
+            Event::MappingEnd | Event::SequenceEnd => {
+                self.stack.pop();
+                self.end_value();
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "\
+profile:
+  name: Jane
+contacts:
+  - platform: GitHub
+    url: https://github.com/jane
+  - platform: Email
+    url: mailto:jane@example.com
+";
+
+    #[test]
+    fn locates_nested_paths() {
+        let spans = SpanIndex::build(SOURCE);
+        assert_eq!(spans.locate("profile.name"), (2, 9));
+        assert_eq!(spans.locate("contacts[1].url"), (7, 10));
+    }
+
+    #[test]
+    fn falls_back_to_ancestors() {
+        let spans = SpanIndex::build(SOURCE);
+        assert_eq!(spans.locate("contacts[0].label"), (4, 13));
+        assert_eq!(spans.locate("education[0]"), (1, 1));
+    }
+
+    #[test]
+    fn fails_only_on_errors() {
+        let mut diagnostics = Diagnostics::new("resume.yaml", SOURCE);
+        diagnostics.warn("contacts", "no contacts listed");
+        let warnings = diagnostics.finish().unwrap();
+        assert_eq!(warnings[0].to_string(), "resume.yaml:4:3: warning: contacts: no contacts listed");
+
+        let mut diagnostics = Diagnostics::new("resume.yaml", SOURCE);
+        diagnostics.require("profile.name", " ");
+        match diagnostics.finish() {
+            Err(Error::Invalid { diagnostics, .. }) => {
+                assert_eq!(diagnostics[0].to_string(), "resume.yaml:2:9: error: profile.name: must not be empty");
+            }
+            _ => panic!("expected a validation error"),
+        }
+    }
+}
@@ -0,0 +1,223 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+//! Content comparison of two resumes. Entries are matched by identity
+//! (skill group id, company and position, institution, platform) rather than
+//! by position, so reordering alone is not a change; entries sharing an
+//! identity pair up in order. Only the content is compared: variants and
+//! tags, labels and the `pdf`, `theme` and `contributions` settings are not.
+
+use crate::Resume;
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Change {
+    Added { path: String, value: String },
+    Removed { path: String, value: String },
+    Changed { path: String, old: String, new: String },
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Change::Added { path, value } => write!(f, "+ {}: {}", path, value),
+            Change::Removed { path, value } => write!(f, "- {}: {}", path, value),
+            Change::Changed { path, old, new } => write!(f, "~ {}: {} → {}", path, old, new),
+        }
+    }
+}
+
+/// Changes from `old` to `new`, in the order of `old` followed by whatever
+/// only `new` has.
+pub fn diff(old: &Resume, new: &Resume) -> Vec<Change> {
+    let old = fields(old);
+    let new = fields(new);
+    // Each field of `new` pairs with at most one of `old`, so repeated list
+    // items pair up one by one.
+    let mut paired = vec![false; new.len()];
+    let mut changes = Vec::new();
+
+    for field in &old {
+        match (0..new.len()).find(|&i| !paired[i] && new[i].matches(field)) {
+            None => changes.push(Change::Removed { path: field.path.clone(), value: field.value.clone() }),
+            Some(i) => {
+                paired[i] = true;
+                if new[i].value != field.value {
+                    changes.push(Change::Changed {
+                        path: field.path.clone(),
+                        old: field.value.clone(),
+                        new: new[i].value.clone(),
+                    });
+                }
+            }
+        }
+    }
+    for (field, paired) in new.iter().zip(paired) {
+        if !paired {
+            changes.push(Change::Added { path: field.path.clone(), value: field.value.clone() });
+        }
+    }
+    changes
+}
+
+struct Field {
+    path: String,
+    value: String,
+    /// List items such as skills and highlights are identified by their text,
+    /// so an edited one shows up as a removal plus an addition.
+    item: bool,
+}
+
+impl Field {
+    fn matches(&self, other: &Field) -> bool {
+        self.path == other.path && (!self.item || self.value == other.value)
+    }
+}
+
+/// Path of the entry of `section` identified by `key`. Entries sharing a
+/// key are numbered from the second on, so they pair up in order.
+fn entry(section: &str, key: String, seen: &mut HashMap<String, usize>) -> String {
+    let count = seen.entry(format!("{}[{}]", section, key)).or_default();
+    *count += 1;
+    match count {
+        1 => format!("{}[{}]", section, key),
+        n => format!("{}[{} #{}]", section, key, n),
+    }
+}
+
+fn fields(resume: &Resume) -> Vec<Field> {
+    let labels = &resume.labels;
+    let mut seen = HashMap::new();
+    let mut fields = Vec::new();
+    let mut push = |path: String, value: &str, item: bool| fields.push(Field { path, value: value.to_string(), item });
+
+    push("profile.name".to_string(), &resume.profile.name, false);
+    push("profile.title".to_string(), &resume.profile.title, false);
+    push("profile.summary".to_string(), resume.profile.summary.text(), false);
+
+    for group in &resume.skills {
+        let path = format!("skills[{}]", group.id);
+        push(format!("{}.label", path), &group.label, false);
+        if let Some(pdf_label) = &group.pdf_label {
+            push(format!("{}.pdf_label", path), pdf_label, false);
+        }
+        for skill in &group.items {
+            push(format!("{}.items", path), skill.text(), true);
+        }
+    }
+    for exp in &resume.experience {
+        let path = entry("experience", format!("{} — {}", exp.company, exp.position), &mut seen);
+        push(format!("{}.period", path), &exp.period(labels), false);
+        push(format!("{}.location", path), exp.location.as_deref().unwrap_or_default(), false);
+        for highlight in &exp.highlights {
+            push(format!("{}.highlights", path), highlight.text(), true);
+        }
+    }
+    for edu in &resume.education {
+        let path = entry("education", edu.institution.clone(), &mut seen);
+        push(format!("{}.degree", path), &edu.degree, false);
+        push(format!("{}.field", path), edu.field.as_deref().unwrap_or_default(), false);
+        push(format!("{}.period", path), edu.period.as_deref().unwrap_or_default(), false);
+        push(format!("{}.location", path), edu.location.as_deref().unwrap_or_default(), false);
+        for note in &edu.notes {
+            push(format!("{}.notes", path), note, true);
+        }
+    }
+    for contact in &resume.contacts {
+        let path = entry("contacts", contact.platform.clone(), &mut seen);
+        push(format!("{}.url", path), &contact.url, false);
+        push(format!("{}.label", path), &contact.label, false);
+    }
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::load;
+
+    const OLD: &str = "\
+profile: { name: Jane Doe, title: Engineer, summary: Builds things. }
+skills:
+  - { id: rust, label: Rust, items: [Tokio, Serde] }
+experience:
+  - company: Acme
+    position: Engineer
+    start: 2019-09
+    end: 2021-06
+    location: Kyiv
+    highlights: [Shipped the API, Mentored interns]
+contacts:
+  - { platform: GitHub, url: https://github.com/jane, label: jane }
+";
+
+    fn resume(source: &str) -> Resume {
+        load("resume.yaml", source).unwrap().resume
+    }
+
+    #[test]
+    fn ignores_identical_resumes() {
+        assert!(diff(&resume(OLD), &resume(OLD)).is_empty());
+    }
+
+    #[test]
+    fn ignores_reordered_items() {
+        let new = OLD.replace("[Tokio, Serde]", "[Serde, Tokio]");
+        assert!(diff(&resume(OLD), &resume(&new)).is_empty());
+    }
+
+    #[test]
+    fn reports_changes() {
+        let new = OLD
+            .replace("title: Engineer", "title: Senior Engineer")
+            .replace("Tokio, Serde", "Tokio, Axum")
+            .replace("location: Kyiv", "location: Remote")
+            .replace("github.com/jane", "github.com/jane-doe");
+        let changes: Vec<String> = diff(&resume(OLD), &resume(&new)).iter().map(ToString::to_string).collect();
+        assert_eq!(
+            changes,
+            [
+                "~ profile.title: Engineer → Senior Engineer",
+                "- skills[rust].items: Serde",
+                "~ experience[Acme — Engineer].location: Kyiv → Remote",
+                "~ contacts[GitHub].url: https://github.com/jane → https://github.com/jane-doe",
+                "+ skills[rust].items: Axum",
+            ]
+        );
+    }
+
+    #[test]
+    fn pairs_entries_sharing_an_identity_in_order() {
+        let old = OLD.replace(
+            "contacts:\n",
+            "  - { company: Acme, position: Engineer, start: 2021-07, location: Lviv, highlights: [Led] }\ncontacts:\n",
+        ) + "  - { platform: GitHub, url: https://github.com/jane-work, label: jane-work }\n";
+        let new = old.replace("location: Lviv", "location: Remote").replace("label: jane-work", "label: work");
+        let changes: Vec<String> = diff(&resume(&old), &resume(&new)).iter().map(ToString::to_string).collect();
+        assert_eq!(
+            changes,
+            [
+                "~ experience[Acme — Engineer].location: Lviv → Remote",
+                "~ contacts[GitHub #2].label: jane-work → work",
+            ]
+        );
+    }
+
+    #[test]
+    fn pairs_repeated_items_one_by_one() {
+        let old = OLD.replace("[Tokio, Serde]", "[Tokio, Tokio]");
+        let changes: Vec<String> = diff(&resume(&old), &resume(OLD)).iter().map(ToString::to_string).collect();
+        assert_eq!(changes, ["- skills[rust].items: Tokio", "+ skills[rust].items: Serde"]);
+    }
+
+    #[test]
+    fn reports_website_and_pdf_labels_apart() {
+        let old = OLD.replace("label: Rust,", "label: Rust, pdf_label: Rust (PDF),");
+        let new = old.replace("label: Rust,", "label: Rust language,");
+        let changes: Vec<String> = diff(&resume(&old), &resume(&new)).iter().map(ToString::to_string).collect();
+        assert_eq!(changes, ["~ skills[rust].label: Rust → Rust language"]);
+
+        let changes: Vec<String> = diff(&resume(OLD), &resume(&old)).iter().map(ToString::to_string).collect();
+        assert_eq!(changes, ["+ skills[rust].pdf_label: Rust (PDF)"]);
+    }
+}
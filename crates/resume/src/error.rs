@@ -0,0 +1,65 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+use crate::Diagnostic;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug)]
+pub enum Error {
+    Io { path: PathBuf, source: io::Error },
+    /// The source failed to parse or validate; `diagnostics` also holds the
+    /// warnings found alongside the errors.
+    Invalid { file: String, diagnostics: Vec<Diagnostic> },
+    Json(serde_json::Error),
+    Format(fmt::Error),
+    /// Typst could not be run or rejected the document.
+    Typst(String),
+    /// A request to an outside service failed or got an unexpected answer.
+    Http(String),
+}
+
+impl Error {
+    pub fn io(path: impl AsRef<Path>, source: io::Error) -> Self {
+        Error::Io { path: path.as_ref().to_path_buf(), source }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            Error::Invalid { file, diagnostics } => {
+                let errors = diagnostics.iter().filter(|d| d.severity == crate::Severity::Error).count();
+                write!(f, "{}: {} error(s)", file, errors)
+            }
+            Error::Json(e) => write!(f, "failed to serialize JSON: {}", e),
+            Error::Format(e) => write!(f, "failed to render: {}", e),
+            Error::Typst(message) => write!(f, "typst: {}", message),
+            Error::Http(message) => write!(f, "request failed: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Json(e) => Some(e),
+            Error::Format(e) => Some(e),
+            Error::Invalid { .. } | Error::Typst(_) | Error::Http(_) => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Json(e)
+    }
+}
+
+impl From<fmt::Error> for Error {
+    fn from(e: fmt::Error) -> Self {
+        Error::Format(e)
+    }
+}
@@ -0,0 +1,176 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+//! `<head>` tags of the website: title, description, Open Graph and Twitter
+//! cards with the preview image, canonical URL and a schema.org `Person`,
+//! written into the pre-rendered page.
+
+use crate::{Edition, Error};
+use serde::Serialize;
+use std::fmt::Write;
+
+/// File name of the social preview card from [`crate::typst::card`], served
+/// next to the page.
+pub const CARD_IMAGE: &str = "og.png";
+const CARD_WIDTH: u32 = 1200;
+const CARD_HEIGHT: u32 = 630;
+
+/// Search engines cut descriptions at around this many characters.
+const DESCRIPTION_LENGTH: usize = 160;
+
+#[derive(Serialize)]
+struct Person<'a> {
+    #[serde(rename = "@context")]
+    context: &'static str,
+    #[serde(rename = "@type")]
+    kind: &'static str,
+    name: &'a str,
+    #[serde(rename = "jobTitle")]
+    job_title: &'a str,
+    description: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    telephone: Option<&'a str>,
+    #[serde(rename = "sameAs", skip_serializing_if = "Vec::is_empty")]
+    same_as: Vec<&'a str>,
+    #[serde(rename = "worksFor", skip_serializing_if = "Vec::is_empty")]
+    works_for: Vec<Organization<'a>>,
+    #[serde(rename = "alumniOf", skip_serializing_if = "Vec::is_empty")]
+    alumni_of: Vec<Organization<'a>>,
+    #[serde(rename = "knowsAbout", skip_serializing_if = "Vec::is_empty")]
+    knows_about: Vec<&'a str>,
+}
+
+#[derive(Serialize)]
+struct Organization<'a> {
+    #[serde(rename = "@type")]
+    kind: &'static str,
+    name: &'a str,
+}
+
+impl<'a> Person<'a> {
+    /// Current employers are the entries without an end date; `http(s)`
+    /// contacts become `sameAs` profiles.
+    fn new(edition: &'a Edition) -> Self {
+        let resume = &edition.resume;
+        let profile = &resume.profile;
+        let contact_value = |scheme: &str| resume.contacts.iter().find_map(|c| c.url.strip_prefix(scheme));
+
+        Person {
+            context: "https://schema.org",
+            kind: "Person",
+            name: &profile.name,
+            job_title: &profile.title,
+            description: profile.summary.text(),
+            url: profile.url.as_deref(),
+            email: contact_value("mailto:"),
+            telephone: contact_value("tel:"),
+            same_as: resume
+                .contacts
+                .iter()
+                .filter(|c| c.url.starts_with("http://") || c.url.starts_with("https://"))
+                .map(|c| c.url.as_str())
+                .collect(),
+            works_for: resume
+                .experience
+                .iter()
+                .filter(|exp| exp.end.is_none())
+                .map(|exp| Organization { kind: "Organization", name: &exp.company })
+                .collect(),
+            alumni_of: resume
+                .education
+                .iter()
+                .map(|edu| Organization { kind: "EducationalOrganization", name: &edu.institution })
+                .collect(),
+            knows_about: resume.skills.iter().flat_map(|group| &group.items).map(|item| item.text()).collect(),
+        }
+    }
+}
+
+/// Tags for the `<head>` of the page showing `edition`, one per line.
+pub fn head(edition: &Edition) -> Result<String, Error> {
+    let profile = &edition.resume.profile;
+    let title = escape(&format!("{} | {}", profile.name, profile.title));
+    let description = escape(&description(profile.summary.text()));
+    let image = escape(&card_url(profile.url.as_deref()));
+    let mut out = String::new();
+
+    writeln!(out, "<title>{}</title>", title)?;
+    writeln!(out, "<meta name=\"description\" content=\"{}\">", description)?;
+    if let Some(url) = &profile.url {
+        writeln!(out, "<link rel=\"canonical\" href=\"{}\">", escape(url))?;
+    }
+    writeln!(out, "<meta property=\"og:type\" content=\"profile\">")?;
+    writeln!(out, "<meta property=\"og:title\" content=\"{}\">", title)?;
+    writeln!(out, "<meta property=\"og:description\" content=\"{}\">", description)?;
+    if let Some(url) = &profile.url {
+        writeln!(out, "<meta property=\"og:url\" content=\"{}\">", escape(url))?;
+    }
+    writeln!(out, "<meta property=\"og:image\" content=\"{}\">", image)?;
+    writeln!(out, "<meta property=\"og:image:width\" content=\"{}\">", CARD_WIDTH)?;
+    writeln!(out, "<meta property=\"og:image:height\" content=\"{}\">", CARD_HEIGHT)?;
+    writeln!(out, "<meta property=\"og:image:alt\" content=\"{}\">", title)?;
+    writeln!(out, "<meta name=\"twitter:card\" content=\"summary_large_image\">")?;
+    writeln!(out, "<meta name=\"twitter:title\" content=\"{}\">", title)?;
+    writeln!(out, "<meta name=\"twitter:description\" content=\"{}\">", description)?;
+    writeln!(out, "<meta name=\"twitter:image\" content=\"{}\">", image)?;
+    writeln!(out, "<script type=\"application/ld+json\">")?;
+    writeln!(out, "{}", script_json(&serde_json::to_string_pretty(&Person::new(edition))?))?;
+    writeln!(out, "</script>")?;
+    Ok(out)
+}
+
+/// Address of the card; crawlers need it absolute, so it is resolved against
+/// the site address when the profile has one.
+fn card_url(site: Option<&str>) -> String {
+    site.and_then(|site| url::Url::parse(site).ok())
+        .and_then(|site| site.join(CARD_IMAGE).ok())
+        .map_or_else(|| CARD_IMAGE.to_string(), String::from)
+}
+
+/// First `DESCRIPTION_LENGTH` characters of `summary`, cut at a word.
+fn description(summary: &str) -> String {
+    if summary.chars().count() <= DESCRIPTION_LENGTH {
+        return summary.to_string();
+    }
+    let cut: String = summary.chars().take(DESCRIPTION_LENGTH - 1).collect();
+    let cut = cut.rsplit_once(' ').map_or(cut.as_str(), |(words, _)| words);
+    format!("{}…", cut.trim_end_matches([' ', ',', ';', ':', '.']))
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+/// JSON that cannot close the surrounding `<script>`; the characters only
+/// occur inside strings, where the escapes read the same.
+fn script_json(json: &str) -> String {
+    json.replace('<', "\\u003c").replace('>', "\\u003e").replace('&', "\\u0026")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shortens_descriptions_at_words() {
+        assert_eq!(description("Short summary."), "Short summary.");
+        let long = "word ".repeat(40);
+        let short = description(&long);
+        assert!(short.chars().count() <= DESCRIPTION_LENGTH);
+        assert!(short.ends_with("word…"));
+    }
+
+    #[test]
+    fn resolves_card_against_site() {
+        assert_eq!(card_url(Some("https://example.com/site/")), "https://example.com/site/og.png");
+        assert_eq!(card_url(None), "og.png");
+    }
+
+    #[test]
+    fn keeps_script_closed() {
+        assert_eq!(script_json(r#"{"a":"</script>"}"#), r#"{"a":"\u003c/script\u003e"}"#);
+    }
+}
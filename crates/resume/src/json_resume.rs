@@ -0,0 +1,491 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+//! Conversion from and to the JSON Resume schema (jsonresume.org).
+
+use crate::diagnostics::Diagnostics;
+use crate::validate::period_bounds;
+use crate::{Contact, Edition, Education, Error, Experience, Labels, Profile, Resume, SkillGroup, Summary, Tagged, YearMonth};
+use serde::{Deserialize, Serialize};
+
+const JSON_RESUME_SCHEMA: &str = "https://raw.githubusercontent.com/jsonresume/resume-schema/v1.0.0/schema.json";
+
+/// Pretty-printed JSON Resume document for `edition`; `version` is recorded
+/// in `meta.version`.
+pub fn export(edition: &Edition, version: &str) -> Result<String, Error> {
+    Ok(serde_json::to_string_pretty(&JsonResume::new(edition, version))? + "\n")
+}
+
+/// JSON Resume (jsonresume.org) document accepted in place of `resume.yaml`.
+/// Sections without a counterpart in the model (awards, interests, ...) are
+/// ignored.
+#[derive(Deserialize)]
+pub(crate) struct JsonResumeSource {
+    basics: JsonBasicsSource,
+    #[serde(default)]
+    work: Vec<JsonWorkSource>,
+    #[serde(default)]
+    education: Vec<JsonEducationSource>,
+    #[serde(default)]
+    skills: Vec<JsonSkillSource>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct JsonBasicsSource {
+    name: String,
+    label: String,
+    email: Option<String>,
+    phone: Option<String>,
+    url: Option<String>,
+    summary: String,
+    location: Option<JsonLocationSource>,
+    profiles: Vec<JsonProfileSource>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, rename_all = "camelCase")]
+struct JsonLocationSource {
+    city: Option<String>,
+    region: Option<String>,
+    country_code: Option<String>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct JsonProfileSource {
+    network: String,
+    username: String,
+    url: Option<String>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, rename_all = "camelCase")]
+struct JsonWorkSource {
+    name: Option<String>,
+    /// Pre-1.0 name of `name`.
+    company: Option<String>,
+    position: String,
+    location: Option<String>,
+    start_date: String,
+    end_date: Option<String>,
+    summary: Option<String>,
+    highlights: Vec<String>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, rename_all = "camelCase")]
+struct JsonEducationSource {
+    institution: String,
+    area: Option<String>,
+    study_type: String,
+    start_date: Option<String>,
+    end_date: Option<String>,
+    courses: Vec<String>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct JsonSkillSource {
+    name: String,
+    keywords: Vec<String>,
+}
+
+impl JsonResumeSource {
+    pub(crate) fn into_resume(self, d: &mut Diagnostics) -> Resume {
+        let labels = Labels::default();
+        let basics = self.basics;
+
+        let location = basics.location.map(|l| {
+            [l.city, l.region, l.country_code].into_iter().flatten().collect::<Vec<_>>().join(", ")
+        });
+
+        let mut contacts = Vec::new();
+        if let Some(email) = basics.email {
+            contacts.push(Contact { platform: "Email".to_string(), url: format!("mailto:{}", email), label: email });
+        }
+        if let Some(phone) = basics.phone {
+            contacts.push(Contact { platform: "Phone".to_string(), url: format!("tel:{}", phone), label: phone });
+        }
+        if let Some(url) = &basics.url {
+            contacts.push(Contact { platform: "Website".to_string(), url: url.clone(), label: url.clone() });
+        }
+        for (i, profile) in basics.profiles.into_iter().enumerate() {
+            match profile.url {
+                Some(url) => contacts.push(Contact { platform: profile.network, url, label: profile.username }),
+                None => d.warn(&format!("basics.profiles[{}]", i), "profile without `url` skipped"),
+            }
+        }
+
+        let experience = self
+            .work
+            .into_iter()
+            .enumerate()
+            .map(|(i, work)| {
+                let mut date = |field: &str, value: &str| {
+                    parse_iso_month(value).unwrap_or_else(|| {
+                        d.error(&format!("work[{}].{}", i, field), format!("invalid date `{}`, expected YYYY-MM-DD", value));
+                        YearMonth(0)
+                    })
+                };
+                let start = date("startDate", &work.start_date);
+                let end = work.end_date.as_deref().map(|value| date("endDate", value));
+                let highlights = if work.highlights.is_empty() { work.summary.into_iter().collect() } else { work.highlights };
+                Experience {
+                    company: work.name.or(work.company).unwrap_or_default(),
+                    position: work.position,
+                    start,
+                    end,
+                    location: work.location.or_else(|| location.clone()),
+                    highlights: highlights.into_iter().map(Tagged::Plain).collect(),
+                }
+            })
+            .collect();
+
+        let education = self
+            .education
+            .into_iter()
+            .enumerate()
+            .map(|(i, edu)| {
+                let mut label = |field: &str, value: Option<&str>| {
+                    let value = value?;
+                    let label = parse_iso_month(value).map(|ym| ym.label(&labels));
+                    if label.is_none() {
+                        d.error(&format!("education[{}].{}", i, field), format!("invalid date `{}`, expected YYYY-MM-DD", value));
+                    }
+                    label
+                };
+                let start = label("startDate", edu.start_date.as_deref());
+                let end = label("endDate", edu.end_date.as_deref());
+                // Either date may be missing; an end date alone becomes the
+                // period.
+                let period = match (start, end) {
+                    (Some(start), Some(end)) => Some(format!("{} — {}", start, end)),
+                    (Some(start), None) => Some(format!("{} — {}", start, labels.present)),
+                    (None, end) => end,
+                };
+                Education {
+                    institution: edu.institution,
+                    degree: edu.study_type,
+                    field: edu.area,
+                    period,
+                    location: None,
+                    notes: edu.courses,
+                }
+            })
+            .collect();
+
+        let skills = self
+            .skills
+            .into_iter()
+            .map(|skill| {
+                let items = if skill.keywords.is_empty() { vec![skill.name.clone()] } else { skill.keywords };
+                SkillGroup {
+                    id: slug(&skill.name),
+                    label: skill.name,
+                    pdf_label: None,
+                    tags: Vec::new(),
+                    items: items.into_iter().map(Tagged::Plain).collect(),
+                }
+            })
+            .collect();
+
+        Resume {
+            labels,
+            variants: Vec::new(),
+            profile: Profile {
+                name: basics.name,
+                title: basics.label,
+                summary: Summary::Plain(basics.summary),
+                url: basics.url,
+            },
+            skills,
+            experience,
+            education,
+            contacts,
+            pdf: None,
+            theme: None,
+            contributions: None,
+        }
+    }
+}
+
+/// Month of an ISO 8601 date (`YYYY-MM-DD`, `YYYY-MM` or `YYYY`, which counts
+/// as January).
+fn parse_iso_month(s: &str) -> Option<YearMonth> {
+    match s.len() {
+        4 => YearMonth::parse(&format!("{}-01", s)),
+        7 => YearMonth::parse(s),
+        10 if s.as_bytes()[7] == b'-' => YearMonth::parse(&s[..7]),
+        _ => None,
+    }
+}
+
+fn slug(s: &str) -> String {
+    let slug: String = s.chars().map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '-' }).collect();
+    slug.split('-').filter(|part| !part.is_empty()).collect::<Vec<_>>().join("-")
+}
+
+/// Translates a model path such as `experience[2].company` into the JSON
+/// Resume field it was read from, `work[2].name`. Contacts are assembled from
+/// several `basics` fields and point at `basics` as a whole.
+pub(crate) fn json_resume_path(path: &str) -> String {
+    let (head, field) = path.split_once('.').unwrap_or((path, ""));
+    let section = head.split('[').next().unwrap_or(head);
+    let head = match section {
+        "profile" | "contacts" => "basics".to_string(),
+        "experience" => head.replacen("experience", "work", 1),
+        _ => head.to_string(),
+    };
+    let field = match (section, field) {
+        ("profile", "title") => "label",
+        ("experience", "company") => "name",
+        ("experience", "start") => "startDate",
+        ("experience", "end") => "endDate",
+        ("education", "degree") => "studyType",
+        ("education", "field") => "area",
+        ("education", "period") => "startDate",
+        ("education", "notes") => "courses",
+        ("skills", "label") => "name",
+        ("skills", "items") => "keywords",
+        ("contacts", _) => "",
+        (_, field) => field,
+    };
+    if field.is_empty() {
+        head
+    } else {
+        format!("{}.{}", head, field)
+    }
+}
+
+/// Document following the JSON Resume schema (jsonresume.org).
+#[derive(Serialize)]
+struct JsonResume<'a> {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    basics: JsonBasics<'a>,
+    work: Vec<JsonWork<'a>>,
+    education: Vec<JsonEducation<'a>>,
+    skills: Vec<JsonSkill<'a>>,
+    meta: JsonMeta,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonBasics<'a> {
+    name: &'a str,
+    label: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    phone: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<&'a str>,
+    summary: &'a str,
+    profiles: Vec<JsonProfile<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonProfile<'a> {
+    network: &'a str,
+    username: &'a str,
+    url: &'a str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonWork<'a> {
+    name: &'a str,
+    position: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location: Option<&'a str>,
+    start_date: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_date: Option<String>,
+    highlights: Vec<&'a str>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonEducation<'a> {
+    institution: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    area: Option<&'a str>,
+    study_type: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_date: Option<String>,
+    courses: Vec<&'a str>,
+}
+
+#[derive(Serialize)]
+struct JsonSkill<'a> {
+    name: &'a str,
+    keywords: Vec<&'a str>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonMeta {
+    version: String,
+    language: String,
+}
+
+impl<'a> JsonResume<'a> {
+    /// `mailto:` and `tel:` contacts become `email`/`phone`; every other
+    /// contact is listed as a profile on its platform.
+    fn new(edition: &'a Edition, version: &str) -> Self {
+        let resume = &edition.resume;
+        let labels = &resume.labels;
+        let contact_value = |scheme: &str| resume.contacts.iter().find_map(|c| c.url.strip_prefix(scheme));
+
+        JsonResume {
+            schema: JSON_RESUME_SCHEMA,
+            basics: JsonBasics {
+                name: &resume.profile.name,
+                label: &resume.profile.title,
+                email: contact_value("mailto:"),
+                phone: contact_value("tel:"),
+                url: resume.profile.url.as_deref(),
+                summary: resume.profile.summary.text(),
+                profiles: resume
+                    .contacts
+                    .iter()
+                    .filter(|c| !c.url.starts_with("mailto:") && !c.url.starts_with("tel:"))
+                    .map(|c| JsonProfile { network: &c.platform, username: &c.label, url: &c.url })
+                    .collect(),
+            },
+            work: resume
+                .experience
+                .iter()
+                .map(|exp| JsonWork {
+                    name: &exp.company,
+                    position: &exp.position,
+                    location: exp.location.as_deref(),
+                    start_date: exp.start.iso(),
+                    end_date: exp.end.map(YearMonth::iso),
+                    highlights: exp.highlights.iter().map(Tagged::text).collect(),
+                })
+                .collect(),
+            education: resume
+                .education
+                .iter()
+                .map(|edu| {
+                    let (start_date, end_date) = edu.period.as_deref().map_or((None, None), |period| period_bounds(period, labels));
+                    JsonEducation {
+                        institution: &edu.institution,
+                        area: edu.field.as_deref(),
+                        study_type: &edu.degree,
+                        start_date,
+                        end_date,
+                        courses: edu.notes.iter().map(String::as_str).collect(),
+                    }
+                })
+                .collect(),
+            skills: resume
+                .skills
+                .iter()
+                .map(|group| JsonSkill {
+                    name: group.pdf_label(),
+                    keywords: group.items.iter().map(Tagged::text).collect(),
+                })
+                .collect(),
+            meta: JsonMeta {
+                version: version.to_string(),
+                language: edition.locale.clone(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::load;
+
+    #[test]
+    fn parses_iso_months() {
+        assert_eq!(parse_iso_month("2020-03-15").map(YearMonth::iso).as_deref(), Some("2020-03"));
+        assert_eq!(parse_iso_month("2020-03").map(YearMonth::iso).as_deref(), Some("2020-03"));
+        assert_eq!(parse_iso_month("2020").map(YearMonth::iso).as_deref(), Some("2020-01"));
+        assert_eq!(parse_iso_month("March 2020"), None);
+    }
+
+    #[test]
+    fn slugs_skill_names() {
+        assert_eq!(slug("Cloud & DevOps"), "cloud-devops");
+        assert_eq!(slug("C++"), "c");
+    }
+
+    #[test]
+    fn maps_model_paths() {
+        assert_eq!(json_resume_path("experience[2].company"), "work[2].name");
+        assert_eq!(json_resume_path("profile.title"), "basics.label");
+        assert_eq!(json_resume_path("contacts[0].url"), "basics");
+        assert_eq!(json_resume_path("skills[1].items"), "skills[1].keywords");
+    }
+
+    #[test]
+    fn reads_json_resume_documents() {
+        let source = r#"{
+  "basics": {
+    "name": "Jane Doe",
+    "label": "Engineer",
+    "email": "jane@example.com",
+    "summary": "Builds things.",
+    "location": { "city": "Kyiv", "countryCode": "UA" },
+    "profiles": [{ "network": "GitHub", "username": "jane", "url": "https://github.com/jane" }]
+  },
+  "work": [{ "name": "Acme", "position": "Engineer", "startDate": "2019-09-01", "endDate": "2021-06-30", "highlights": ["Shipped"] }],
+  "skills": [{ "name": "Cloud & DevOps", "keywords": ["AWS"] }]
+}"#;
+        let resume = load("resume.json", source).unwrap().resume;
+        assert_eq!(resume.experience[0].location.as_deref(), Some("Kyiv, UA"));
+        assert_eq!(resume.experience[0].period(&resume.labels), "Sep 2019 — Jun 2021");
+        assert_eq!(resume.skills[0].id, "cloud-devops");
+        assert_eq!(resume.contacts[0].url, "mailto:jane@example.com");
+        assert_eq!(resume.contacts[1].platform, "GitHub");
+    }
+
+    #[test]
+    fn reads_entries_without_optional_fields() {
+        let source = r#"{
+  "basics": { "name": "Jane Doe", "label": "Engineer", "summary": "Builds things.", "email": "jane@example.com" },
+  "work": [{ "name": "Acme", "position": "Engineer", "startDate": "2019-09-01", "highlights": ["Shipped"] }],
+  "education": [
+    { "institution": "KPI", "studyType": "MSc", "endDate": "2013-06-30" },
+    { "institution": "School", "studyType": "Diploma" }
+  ]
+}"#;
+        let resume = load("resume.json", source).unwrap().resume;
+        assert_eq!(resume.experience[0].location, None);
+        assert_eq!(resume.education[0].period.as_deref(), Some("Jun 2013"));
+        assert_eq!(resume.education[1].period, None);
+    }
+
+    #[test]
+    fn points_json_errors_at_source_fields() {
+        let source = r#"{
+  "basics": { "name": "Jane Doe", "label": "", "summary": "x", "email": "jane@example.com" },
+  "work": [{ "name": "Acme", "position": "Engineer", "startDate": "soon", "highlights": ["Shipped"] }]
+}"#;
+        let Err(Error::Invalid { diagnostics, .. }) = load("resume.json", source) else {
+            panic!("expected a validation error");
+        };
+        let messages: Vec<String> = diagnostics.iter().map(|d| d.message.clone()).collect();
+        assert!(messages.contains(&"work[0].startDate: invalid date `soon`, expected YYYY-MM-DD".to_string()));
+        assert!(messages.contains(&"basics.label: must not be empty".to_string()));
+    }
+
+    #[test]
+    fn reports_invalid_education_dates() {
+        let source = r#"{
+  "basics": { "name": "Jane Doe", "label": "Engineer", "summary": "x", "email": "jane@example.com" },
+  "education": [{ "institution": "KPI", "studyType": "MSc", "startDate": "autumn" }]
+}"#;
+        let Err(Error::Invalid { diagnostics, .. }) = load("resume.json", source) else {
+            panic!("expected a validation error");
+        };
+        assert_eq!(diagnostics[0].message, "education[0].startDate: invalid date `autumn`, expected YYYY-MM-DD");
+    }
+}
@@ -0,0 +1,40 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+//! Resume model shared by the website build and the `resume` CLI: loading
+//! and validating sources, selecting variants, and rendering them as Rust
+//! data, PDF, JSON Resume, Markdown and plain text, plus the theme
+//! stylesheet, page metadata and the contributions search.
+
+pub mod codegen;
+pub mod contributions;
+pub mod css;
+mod diagnostics;
+pub mod diff;
+mod error;
+pub mod html;
+pub mod json_resume;
+mod load;
+pub mod markdown;
+mod model;
+pub mod text;
+pub mod typst;
+mod validate;
+
+pub use diagnostics::{Diagnostic, Severity};
+pub use error::Error;
+pub use load::{load, load_file, locale_sources, Loaded, Source};
+pub use model::*;
+
+/// Default-locale source in the repository's own format.
+pub const RESUME_PATH: &str = "resume.yaml";
+/// Default-locale source in JSON Resume format, used without `resume.yaml`.
+pub const JSON_SOURCE_PATH: &str = "resume.json";
+pub const SCHEMA_PATH: &str = "resume.schema.json";
+pub const DEFAULT_LOCALE: &str = "en";
+/// Edition holding everything, whatever the tags.
+pub const FULL_VARIANT: &str = "full";
+
+/// JSON Schema of `resume.yaml`, pretty-printed.
+pub fn schema() -> Result<String, Error> {
+    Ok(serde_json::to_string_pretty(&schemars::schema_for!(Resume))? + "\n")
+}
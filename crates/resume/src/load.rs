@@ -0,0 +1,199 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+use crate::diagnostics::{Diagnostic, Diagnostics, Severity};
+use crate::json_resume::{json_resume_path, JsonResumeSource};
+use crate::validate::{check_timeline, check_variants, validate};
+use crate::{Error, Resume, DEFAULT_LOCALE, JSON_SOURCE_PATH, RESUME_PATH, SCHEMA_PATH};
+use std::fs;
+use std::path::Path;
+
+/// Source file of one locale, named relative to the resume directory.
+pub struct Source {
+    pub locale: String,
+    pub file: String,
+}
+
+/// A resume that passed validation, with the warnings raised on the way.
+pub struct Loaded {
+    pub resume: Resume,
+    pub warnings: Vec<Diagnostic>,
+}
+
+/// `resume.yaml` holds the default locale, or `resume.json` in JSON Resume
+/// format when there is no YAML file. Translations live next to it as
+/// `resume.<lang>.yaml` or `resume.<lang>.json`.
+pub fn locale_sources(dir: &Path) -> Result<Vec<Source>, Error> {
+    let entries = fs::read_dir(dir).map_err(|e| Error::io(dir, e))?;
+    let mut translations: Vec<(String, String)> = entries
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|name| name != SCHEMA_PATH)
+        .filter_map(|name| {
+            let stem = name.strip_prefix("resume.")?;
+            let code = stem.strip_suffix(".yaml").or_else(|| stem.strip_suffix(".json"))?;
+            let valid = !code.is_empty() && code.chars().all(|c| c.is_ascii_alphanumeric() || c == '-');
+            valid.then(|| (code.to_string(), name.clone()))
+        })
+        .collect();
+    translations.sort();
+    translations.dedup_by(|a, b| a.0 == b.0);
+
+    let default_file = if dir.join(RESUME_PATH).exists() || !dir.join(JSON_SOURCE_PATH).exists() {
+        RESUME_PATH
+    } else {
+        JSON_SOURCE_PATH
+    };
+    let mut sources = vec![Source { locale: DEFAULT_LOCALE.to_string(), file: default_file.to_string() }];
+    sources.extend(
+        translations
+            .into_iter()
+            .filter(|(code, _)| code != DEFAULT_LOCALE)
+            .map(|(locale, file)| Source { locale, file }),
+    );
+    Ok(sources)
+}
+
+/// Reads and loads `path`, naming it as given in diagnostics.
+pub fn load_file(path: &Path) -> Result<Loaded, Error> {
+    let source = fs::read_to_string(path).map_err(|e| Error::io(path, e))?;
+    load(&path.display().to_string(), &source)
+}
+
+/// Parses and validates `source`, read from `file`; `.json` files are JSON
+/// Resume documents, anything else is `resume.yaml` format. Experience comes
+/// back sorted newest first.
+pub fn load(file: &str, source: &str) -> Result<Loaded, Error> {
+    let mut diagnostics = Diagnostics::new(file, source);
+
+    let parsed = if file.ends_with(".json") {
+        diagnostics.map_path = json_resume_path;
+        serde_json::from_str::<JsonResumeSource>(source)
+            .map(|json| json.into_resume(&mut diagnostics))
+            .map_err(|e| (e.line(), e.column(), e.to_string()))
+    } else {
+        serde_yaml::from_str::<Resume>(source).map_err(|e| {
+            let (line, column) = e.location().map_or((1, 1), |l| (l.line(), l.column()));
+            (line, column, e.to_string())
+        })
+    };
+
+    let mut resume = match parsed {
+        Ok(resume) => resume,
+        Err((line, column, message)) => {
+            let message = message.split(" at line ").next().unwrap_or(&message);
+            diagnostics.emit(Severity::Error, line, column, message);
+            diagnostics.finish()?;
+            unreachable!("parse errors fail `finish`");
+        }
+    };
+
+    validate(&resume, &mut diagnostics);
+    check_variants(&resume, &mut diagnostics);
+    if !diagnostics.has_errors() {
+        check_timeline(&resume.experience, &mut diagnostics);
+    }
+    let warnings = diagnostics.finish()?;
+
+    resume.experience.sort_by(|a, b| b.end_or_today().cmp(&a.end_or_today()).then(b.start.cmp(&a.start)));
+    Ok(Loaded { resume, warnings })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MINIMAL: &str = "\
+profile:
+  name: Jane Doe
+  title: Engineer
+  summary: Builds things.
+skills:
+  - id: rust
+    label: Rust
+    items: [Tokio]
+experience:
+  - company: Old Co
+    position: Intern
+    start: 2015-01
+    end: 2016-12
+    location: Kyiv
+    highlights: [Learned]
+  - company: New Co
+    position: Engineer
+    start: 2017-01
+    end: 2020-06
+    location: Remote
+    highlights: [Shipped]
+contacts:
+  - platform: Email
+    url: mailto:jane@example.com
+    label: jane@example.com
+";
+
+    fn messages(result: Result<Loaded, Error>) -> Vec<String> {
+        match result {
+            Ok(loaded) => loaded.warnings.iter().map(ToString::to_string).collect(),
+            Err(Error::Invalid { diagnostics, .. }) => diagnostics.iter().map(ToString::to_string).collect(),
+            Err(e) => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn sorts_experience_newest_first() {
+        let loaded = load("resume.yaml", MINIMAL).unwrap();
+        assert!(loaded.warnings.is_empty());
+        assert_eq!(loaded.resume.experience[0].company, "New Co");
+    }
+
+    #[test]
+    fn reports_parse_errors_with_location() {
+        let source = MINIMAL.replace("  title: Engineer", "  title: Engineer\n  website: x");
+        assert_eq!(
+            messages(load("resume.yaml", &source)),
+            ["resume.yaml:4:3: error: profile: unknown field `website`, expected one of `name`, `title`, `summary`, `url`"]
+        );
+    }
+
+    #[test]
+    fn reports_invalid_values() {
+        let source = MINIMAL.replace("mailto:jane@example.com", "ftp://example.com").replace("end: 2016-12", "end: 2014-12");
+        assert_eq!(
+            messages(load("resume.yaml", &source)),
+            [
+                "resume.yaml:13:10: error: experience[0].end: ends before it starts",
+                "resume.yaml:24:10: error: contacts[0].url: unsupported URL scheme `ftp`, expected one of http, https, mailto, tel",
+            ]
+        );
+    }
+
+    #[test]
+    fn warns_about_gaps() {
+        let source = MINIMAL.replace("start: 2017-01", "start: 2017-04");
+        assert_eq!(
+            messages(load("resume.yaml", &source)),
+            ["resume.yaml:18:12: warning: experience[1].start: 3 mos gap between `Old Co` and `New Co`"]
+        );
+    }
+
+    #[test]
+    fn warns_about_unselected_tags() {
+        let source = MINIMAL
+            .replace("profile:", "variants:\n  - id: backend\n    tags: [backend]\nprofile:")
+            .replace("items: [Tokio]", "items: [{ text: Tokio, tags: [async] }]");
+        assert_eq!(
+            messages(load("resume.yaml", &source)),
+            [
+                "resume.yaml:3:11: warning: variants[0].tags: tag `backend` is not used by any entry",
+                "resume.yaml:11:34: warning: skills[0].items[0].tags: tag `async` is not selected by any variant",
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_reserved_variant_ids() {
+        let source = MINIMAL.replace("profile:", "variants:\n  - id: full\n    tags: []\nprofile:");
+        assert_eq!(
+            messages(load("resume.yaml", &source)),
+            ["resume.yaml:2:9: error: variants[0].id: `full` is reserved for the complete resume"]
+        );
+    }
+}
@@ -0,0 +1,263 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+//! `resume` command: validate sources, render any edition, export the website
+//! data, snapshot the contributions and compare two revisions without
+//! building the website.
+
+use clap::{Parser, Subcommand, ValueEnum};
+use resume::{codegen, contributions, diff, json_resume, markdown, text, typst, Diagnostic, Edition, Error, Severity};
+use resume::{DEFAULT_LOCALE, FULL_VARIANT};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(version, about = "Validate, render and compare resume sources")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Check sources, reporting problems as `file:line:column` diagnostics.
+    Validate {
+        /// Sources to check; every locale in `--dir` when omitted.
+        files: Vec<PathBuf>,
+        /// Directory holding `resume.yaml` and its translations.
+        #[arg(long, default_value = ".")]
+        dir: PathBuf,
+    },
+    /// Render one edition of the resume.
+    Render {
+        #[arg(long, value_enum, default_value_t = Format::Pdf)]
+        format: Format,
+        /// Variant id from the `variants` block.
+        #[arg(long, default_value = FULL_VARIANT)]
+        variant: String,
+        #[arg(long, default_value = DEFAULT_LOCALE)]
+        locale: String,
+        /// Output file; text formats go to stdout when omitted, PDFs to
+        /// `resume.<lang>[.<variant>].pdf`.
+        #[arg(long)]
+        out: Option<PathBuf>,
+        /// Directory holding `resume.yaml` and its translations.
+        #[arg(long, default_value = ".")]
+        dir: PathBuf,
+    },
+    /// Write the website data of every locale and variant as JSON, which a
+    /// site built with the `runtime-data` feature loads at startup.
+    SiteData {
+        #[arg(long, default_value = "assets/resumes.json")]
+        out: PathBuf,
+        /// Directory holding `resume.yaml` and its translations.
+        #[arg(long, default_value = ".")]
+        dir: PathBuf,
+    },
+    /// Run the requests of the contributions section and save their results,
+    /// which the website shows until live requests succeed. Set
+    /// `GITHUB_TOKEN` to search GitHub with a higher rate limit.
+    Contributions {
+        #[arg(long, default_value = contributions::SNAPSHOT_PATH)]
+        out: PathBuf,
+        /// Directory holding `resume.yaml` and its translations.
+        #[arg(long, default_value = ".")]
+        dir: PathBuf,
+    },
+    /// List content changes between two sources. Exits with 1 when they
+    /// differ and 2 when either fails to load.
+    ///
+    /// Compares the profile, skills, experience, education and contacts.
+    /// Variants and tags, labels and the `pdf`, `theme` and `contributions`
+    /// settings are not compared.
+    Diff { old: PathBuf, new: PathBuf },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Pdf,
+    Md,
+    Json,
+    Txt,
+    /// Data the PDF templates read, for compiling them with the Typst CLI.
+    TypstData,
+    /// Data of the social preview card template.
+    CardData,
+}
+
+fn main() -> ExitCode {
+    match Cli::parse().command {
+        Command::Validate { files, dir } => validate(files, &dir),
+        Command::Render { format, variant, locale, out, dir } => {
+            render(format, &locale, &variant, out, &dir).unwrap_or_else(|e| fail(&e))
+        }
+        Command::SiteData { out, dir } => site_data(&out, &dir).unwrap_or_else(|e| fail(&e)),
+        Command::Contributions { out, dir } => snapshot(&out, &dir).unwrap_or_else(|e| fail(&e)),
+        Command::Diff { old, new } => compare(&old, &new).unwrap_or_else(|e| {
+            fail(&e);
+            ExitCode::from(2)
+        }),
+    }
+}
+
+fn validate(files: Vec<PathBuf>, dir: &Path) -> ExitCode {
+    let files = if files.is_empty() {
+        match resume::locale_sources(dir) {
+            Ok(sources) => sources.iter().map(|source| source_path(dir, &source.file)).collect(),
+            Err(e) => return fail(&e),
+        }
+    } else {
+        files
+    };
+
+    let (mut errors, mut warnings) = (0, 0);
+    for file in &files {
+        let diagnostics = match resume::load_file(file) {
+            Ok(loaded) => loaded.warnings,
+            Err(Error::Invalid { diagnostics, .. }) => diagnostics,
+            Err(e) => {
+                eprintln!("error: {}", e);
+                errors += 1;
+                continue;
+            }
+        };
+        print_diagnostics(&diagnostics);
+        errors += diagnostics.iter().filter(|d| d.severity == Severity::Error).count();
+        warnings += diagnostics.iter().filter(|d| d.severity == Severity::Warning).count();
+    }
+
+    eprintln!("{} file(s) checked: {} error(s), {} warning(s)", files.len(), errors, warnings);
+    if errors > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn render(format: Format, locale: &str, variant: &str, out: Option<PathBuf>, dir: &Path) -> Result<ExitCode, Error> {
+    let editions = load_editions(dir)?;
+    let Some(edition) = editions.iter().find(|e| e.locale == locale && e.variant == variant) else {
+        if editions.iter().any(|e| e.locale == locale) {
+            let variants = list(editions.iter().filter(|e| e.locale == locale).map(|e| e.variant.as_str()));
+            eprintln!("error: unknown variant `{}`, expected one of {}", variant, variants);
+        } else {
+            let locales = list(editions.iter().map(|e| e.locale.as_str()));
+            eprintln!("error: unknown locale `{}`, expected one of {}", locale, locales);
+        }
+        return Ok(ExitCode::FAILURE);
+    };
+
+    let content = match format {
+        Format::Pdf => {
+            let pdf_path = out.unwrap_or_else(|| PathBuf::from(edition.pdf_name()));
+            let pdf = typst::pdf(edition, dir)?;
+            fs::write(&pdf_path, pdf).map_err(|e| Error::io(&pdf_path, e))?;
+            eprintln!("wrote {}", pdf_path.display());
+            return Ok(ExitCode::SUCCESS);
+        }
+        Format::Md => markdown::document(edition)?,
+        Format::Json => json_resume::export(edition, env!("CARGO_PKG_VERSION"))?,
+        Format::Txt => text::document(edition)?,
+        Format::TypstData => typst::input(edition)?,
+        Format::CardData => typst::card_input(edition)?,
+    };
+    match out {
+        Some(path) => fs::write(&path, content).map_err(|e| Error::io(&path, e))?,
+        None => print!("{}", content),
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn site_data(out: &Path, dir: &Path) -> Result<ExitCode, Error> {
+    let json = codegen::json(&load_editions(dir)?)?;
+    if let Some(parent) = out.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent).map_err(|e| Error::io(parent, e))?;
+    }
+    fs::write(out, json).map_err(|e| Error::io(out, e))?;
+    Ok(ExitCode::SUCCESS)
+}
+
+fn snapshot(out: &Path, dir: &Path) -> Result<ExitCode, Error> {
+    let editions = load_editions(dir)?;
+    let Some(config) = editions.first().and_then(|e| e.resume.contributions.as_ref()) else {
+        eprintln!("error: the sources have no `contributions` block");
+        return Ok(ExitCode::FAILURE);
+    };
+
+    let mut responses = Vec::new();
+    for source in contributions::sources(config) {
+        let mut request = ureq::get(&source.url)
+            .header("Accept", "application/json")
+            .header("User-Agent", concat!("resume/", env!("CARGO_PKG_VERSION")));
+        let token = match source.provider {
+            "github" => env::var("GITHUB_TOKEN").ok(),
+            _ => None,
+        };
+        if let Some(token) = token {
+            request = request.header("Authorization", format!("Bearer {}", token));
+        }
+        let body = request
+            .call()
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .map_err(|e| Error::Http(format!("{}: {}", source.url, e)))?;
+        responses.push((source, body));
+    }
+
+    fs::write(out, contributions::snapshot(&responses)?).map_err(|e| Error::io(out, e))?;
+    eprintln!("wrote {}", out.display());
+    Ok(ExitCode::SUCCESS)
+}
+
+fn compare(old: &Path, new: &Path) -> Result<ExitCode, Error> {
+    let old = resume::load_file(old)?;
+    let new = resume::load_file(new)?;
+    print_diagnostics(&old.warnings);
+    print_diagnostics(&new.warnings);
+
+    let changes = diff::diff(&old.resume, &new.resume);
+    for change in &changes {
+        println!("{}", change);
+    }
+    Ok(if changes.is_empty() { ExitCode::SUCCESS } else { ExitCode::FAILURE })
+}
+
+/// Every locale in `dir`, loaded the way the website build does.
+fn load_editions(dir: &Path) -> Result<Vec<Edition>, Error> {
+    let mut locales = Vec::new();
+    for source in resume::locale_sources(dir)? {
+        let loaded = resume::load_file(&source_path(dir, &source.file))?;
+        print_diagnostics(&loaded.warnings);
+        locales.push((source.locale, loaded.resume));
+    }
+    Ok(resume::editions(&locales))
+}
+
+fn source_path(dir: &Path, file: &str) -> PathBuf {
+    if dir == Path::new(".") {
+        PathBuf::from(file)
+    } else {
+        dir.join(file)
+    }
+}
+
+/// Comma-separated names with adjacent repeats removed.
+fn list<'a>(names: impl Iterator<Item = &'a str>) -> String {
+    let mut names: Vec<&str> = names.collect();
+    names.dedup();
+    names.join(", ")
+}
+
+fn print_diagnostics(diagnostics: &[Diagnostic]) {
+    for diagnostic in diagnostics {
+        eprintln!("{}", diagnostic);
+    }
+}
+
+fn fail(e: &Error) -> ExitCode {
+    if let Error::Invalid { diagnostics, .. } = e {
+        print_diagnostics(diagnostics);
+    }
+    eprintln!("error: {}", e);
+    ExitCode::FAILURE
+}
@@ -0,0 +1,121 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+//! CommonMark resume, e.g. for a repository README or a gist.
+
+use crate::model::details;
+use crate::{Edition, Error};
+use std::fmt::Write;
+
+pub fn document(edition: &Edition) -> Result<String, Error> {
+    let resume = &edition.resume;
+    let labels = &resume.labels;
+    let mut out = String::new();
+
+    writeln!(out, "# {}", escape(&resume.profile.name))?;
+    writeln!(out)?;
+    writeln!(out, "**{}**", escape(&resume.profile.title))?;
+    writeln!(out)?;
+    writeln!(out, "{}", escape(resume.profile.summary.text()))?;
+    writeln!(out)?;
+
+    writeln!(out, "## {}", escape(&labels.competencies))?;
+    writeln!(out)?;
+    for group in &resume.skills {
+        let items: Vec<String> = group.items.iter().map(|item| escape(item.text())).collect();
+        writeln!(out, "- **{}:** {}", escape(group.pdf_label()), items.join(", "))?;
+    }
+    writeln!(out)?;
+
+    writeln!(out, "## {}", escape(&labels.experience))?;
+    writeln!(out)?;
+    for exp in &resume.experience {
+        writeln!(out, "### {} — {}", escape(&exp.company), escape(&exp.position))?;
+        writeln!(out)?;
+        let when = format!("{} ({})", exp.period(labels), exp.duration(labels));
+        let location = exp.location.as_deref().map(escape);
+        writeln!(out, "*{}*", details(&[Some(&escape(&when)), location.as_deref()]))?;
+        writeln!(out)?;
+        for highlight in &exp.highlights {
+            writeln!(out, "- {}", escape(highlight.text()))?;
+        }
+        if !exp.highlights.is_empty() {
+            writeln!(out)?;
+        }
+    }
+
+    if !resume.education.is_empty() {
+        writeln!(out, "## {}", escape(&labels.education))?;
+        writeln!(out)?;
+        for edu in &resume.education {
+            let degree = match &edu.field {
+                Some(field) => format!("{}, {}", escape(&edu.degree), escape(field)),
+                None => escape(&edu.degree),
+            };
+            writeln!(out, "### {} — {}", escape(&edu.institution), degree)?;
+            writeln!(out)?;
+            let (period, location) = (edu.period.as_deref().map(escape), edu.location.as_deref().map(escape));
+            let details = details(&[period.as_deref(), location.as_deref()]);
+            if !details.is_empty() {
+                writeln!(out, "*{}*", details)?;
+                writeln!(out)?;
+            }
+            for note in &edu.notes {
+                writeln!(out, "- {}", escape(note))?;
+            }
+            if !edu.notes.is_empty() {
+                writeln!(out)?;
+            }
+        }
+    }
+
+    writeln!(out, "## {}", escape(&labels.contacts))?;
+    writeln!(out)?;
+    for contact in &resume.contacts {
+        writeln!(out, "- {}: [{}](<{}>)", escape(&contact.platform), escape(&contact.label), contact.url.replace('>', "%3E"))?;
+    }
+
+    Ok(out)
+}
+
+/// Backslash-escapes every ASCII punctuation character CommonMark could
+/// read as markup: inline markup and entities anywhere, list markers,
+/// thematic breaks and setext underlines at the start of a line.
+pub fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    // Only spaces so far on this line, or only spaces and then digits.
+    let (mut line_start, mut list_number) = (true, false);
+    for c in s.chars() {
+        let inline = matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#' | '|' | '!' | '~' | '&');
+        let block = line_start && matches!(c, '-' | '+' | '=') || list_number && matches!(c, '.' | ')');
+        if inline || block {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+        list_number = (line_start || list_number) && c.is_ascii_digit();
+        line_start = c == '\n' || line_start && c == ' ';
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_markup() {
+        assert_eq!(escape("C# *fast* [x](y) a_b <br>"), "C\\# \\*fast\\* \\[x\\](y) a\\_b \\<br\\>");
+        assert_eq!(escape("&copy; R&D"), "\\&copy; R\\&D");
+        assert_eq!(escape("- 40% faster"), "\\- 40% faster");
+        assert_eq!(escape("+ more"), "\\+ more");
+        assert_eq!(escape("1. first"), "1\\. first");
+        assert_eq!(escape("2024) done"), "2024\\) done");
+        assert_eq!(escape("---"), "\\---");
+        assert_eq!(escape("==="), "\\===");
+        assert_eq!(escape("Intro\n  - item"), "Intro\n  \\- item");
+    }
+
+    #[test]
+    fn leaves_inline_dashes_and_numbers() {
+        assert_eq!(escape("Kyiv - Remote, v1.2, 3+ years"), "Kyiv - Remote, v1.2, 3+ years");
+    }
+}
@@ -0,0 +1,740 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+use crate::FULL_VARIANT;
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+use serde::{Deserialize, Deserializer};
+use std::borrow::Cow;
+use std::env;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub(crate) const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Resume source read from `resume.yaml` or a `resume.<lang>.yaml` translation.
+#[derive(Clone, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Resume {
+    /// Interface and PDF strings; English where omitted.
+    #[serde(default)]
+    pub labels: Labels,
+    /// Role-targeted editions; translations without their own list reuse the
+    /// variants of `resume.yaml`.
+    #[serde(default)]
+    pub variants: Vec<Variant>,
+    pub profile: Profile,
+    /// Skill groups, rendered in the order listed.
+    pub skills: Vec<SkillGroup>,
+    /// Work history; sorted most recent first during the build.
+    pub experience: Vec<Experience>,
+    #[serde(default)]
+    pub education: Vec<Education>,
+    pub contacts: Vec<Contact>,
+    /// PDF layout; translations without their own block reuse the one of
+    /// `resume.yaml`.
+    pub pdf: Option<Pdf>,
+    /// Colours and fonts of the website, whose accent and fonts the PDFs
+    /// share; inherited like `pdf`.
+    pub theme: Option<Theme>,
+    /// Pull requests listed on the website; the section is left out without
+    /// this block. Inherited like `pdf`.
+    pub contributions: Option<Contributions>,
+}
+
+#[derive(Clone, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct Labels {
+    /// Language name shown in the locale switcher, in that language.
+    pub language: String,
+    pub competencies: String,
+    pub experience: String,
+    pub education: String,
+    pub contributions: String,
+    pub contacts: String,
+    pub period: String,
+    pub location: String,
+    /// Appended to the total years of experience, e.g. `+ years`.
+    pub years_total: String,
+    pub present: String,
+    /// Abbreviated month names, January first.
+    pub months: Vec<String>,
+    pub year: String,
+    pub years: String,
+    pub month: String,
+    pub month_plural: String,
+    pub download: String,
+    pub loading: String,
+    pub no_contributions: String,
+    /// Shown when contributions cannot be loaded.
+    pub contributions_failed: String,
+    /// Shown while the forges rate-limit the contributions requests,
+    /// followed by the time the limit lifts, e.g. `14:05`.
+    pub rate_limited: String,
+    pub retry: String,
+    pub theme_light: String,
+    pub theme_dark: String,
+    /// Theme switcher option following the operating system.
+    pub theme_system: String,
+}
+
+impl Default for Labels {
+    fn default() -> Self {
+        Labels {
+            language: "English".to_string(),
+            competencies: "Core Competencies".to_string(),
+            experience: "Professional Experience".to_string(),
+            education: "Education".to_string(),
+            contributions: "Open Source Contributions".to_string(),
+            contacts: "Contact".to_string(),
+            period: "Period".to_string(),
+            location: "Location".to_string(),
+            years_total: "+ years".to_string(),
+            present: "Present".to_string(),
+            months: MONTHS.iter().map(|m| m.to_string()).collect(),
+            year: "yr".to_string(),
+            years: "yrs".to_string(),
+            month: "mo".to_string(),
+            month_plural: "mos".to_string(),
+            download: "Download Resume".to_string(),
+            loading: "Loading...".to_string(),
+            no_contributions: "No contributions found".to_string(),
+            contributions_failed: "Contributions could not be loaded.".to_string(),
+            rate_limited: "Too many requests for now; updates resume at".to_string(),
+            retry: "Retry".to_string(),
+            theme_light: "Light".to_string(),
+            theme_dark: "Dark".to_string(),
+            theme_system: "System".to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Profile {
+    pub name: String,
+    pub title: String,
+    pub summary: Summary,
+    /// Public `http(s)` address of the website, used as its canonical URL.
+    pub url: Option<String>,
+}
+
+/// A variant keeps untagged content plus anything sharing one of its tags.
+#[derive(Clone, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Variant {
+    /// Value of the `?variant=` query parameter; `full` is reserved.
+    pub id: String,
+    pub tags: Vec<String>,
+}
+
+/// Text shared by every variant, or limited to variants selecting its tags.
+#[derive(Clone, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum Tagged {
+    Plain(String),
+    Tagged { text: String, tags: Vec<String> },
+}
+
+impl Tagged {
+    pub fn text(&self) -> &str {
+        match self {
+            Tagged::Plain(text) | Tagged::Tagged { text, .. } => text,
+        }
+    }
+
+    pub fn tags(&self) -> &[String] {
+        match self {
+            Tagged::Plain(_) => &[],
+            Tagged::Tagged { tags, .. } => tags,
+        }
+    }
+}
+
+/// Either one summary, or alternatives of which each variant shows the first
+/// matching one (the first untagged one when nothing matches).
+#[derive(Clone, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum Summary {
+    Plain(String),
+    Alternatives(Vec<Tagged>),
+}
+
+impl Summary {
+    pub fn pick(&self, selection: Option<&[String]>) -> &str {
+        let alternatives = match self {
+            Summary::Plain(text) => return text,
+            Summary::Alternatives(alternatives) => alternatives,
+        };
+        let matching = selection.and_then(|sel| alternatives.iter().find(|a| a.tags().iter().any(|t| sel.contains(t))));
+        matching
+            .or_else(|| alternatives.iter().find(|a| a.tags().is_empty()))
+            .or(alternatives.first())
+            .map_or("", Tagged::text)
+    }
+
+    pub fn text(&self) -> &str {
+        self.pick(None)
+    }
+}
+
+#[derive(Clone, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SkillGroup {
+    /// Stable identifier, unique across groups.
+    pub id: String,
+    /// Label shown on the website.
+    pub label: String,
+    /// Label used in the PDF; falls back to `label`.
+    pub pdf_label: Option<String>,
+    /// Limits the whole group to variants selecting one of these tags.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub items: Vec<Tagged>,
+}
+
+impl SkillGroup {
+    pub fn pdf_label(&self) -> &str {
+        self.pdf_label.as_ref().unwrap_or(&self.label)
+    }
+}
+
+#[derive(Clone, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Experience {
+    pub company: String,
+    pub position: String,
+    pub start: YearMonth,
+    /// Omitted while the role is ongoing.
+    pub end: Option<YearMonth>,
+    pub location: Option<String>,
+    pub highlights: Vec<Tagged>,
+}
+
+#[derive(Clone, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Education {
+    pub institution: String,
+    pub degree: String,
+    pub field: Option<String>,
+    /// `YYYY — YYYY`, months optional.
+    pub period: Option<String>,
+    pub location: Option<String>,
+    #[serde(default)]
+    pub notes: Vec<String>,
+}
+
+#[derive(Clone, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Contact {
+    pub platform: String,
+    /// Absolute `http(s)`, `mailto` or `tel` URL.
+    pub url: String,
+    pub label: String,
+}
+
+/// Layout and styling of the generated PDFs. Unset values take the
+/// template's own defaults.
+#[derive(Clone, Default, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct Pdf {
+    pub template: Template,
+    /// Font family, falling back to the bundled DejaVu Sans for anything it
+    /// lacks.
+    pub font: Option<String>,
+    /// Font files providing `font`, relative to the directory of the sources.
+    pub fonts: Vec<String>,
+    /// Colour of links and highlights, `#rrggbb`.
+    pub accent: Option<String>,
+    /// Colour of body text, `#rrggbb`.
+    pub text: Option<String>,
+    /// Page margin with a unit, e.g. `1.5cm`, `12mm`, `0.5in` or `36pt`.
+    pub margin: Option<String>,
+}
+
+/// Branding shared by the website and the PDFs: a dark and a light palette
+/// plus font families in order of preference. The website's original
+/// colours are the default.
+#[derive(Clone, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct Theme {
+    /// Colours of the dark mode, shown unless the visitor or their system
+    /// prefers light.
+    pub dark: Palette,
+    /// Colours of the light mode, also used for printing and the PDFs.
+    pub light: Palette,
+    /// Body font, on the website and in the PDFs; generic names such as
+    /// `sans-serif` only apply to the website.
+    pub font: Vec<String>,
+    /// Monospaced font of the website.
+    pub mono: Vec<String>,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        let families = |names: &[&str]| names.iter().map(|name| name.to_string()).collect();
+        Theme {
+            dark: Palette::new(["#58a6ff", "#0d1117", "#161b22", "#30363d", "#e6edf3", "#8b949e", "#3fb950"]),
+            light: Palette::new(["#0969da", "#ffffff", "#f6f8fa", "#d0d7de", "#1f2328", "#656d76", "#1a7f37"]),
+            font: families(&["-apple-system", "BlinkMacSystemFont", "Segoe UI", "Helvetica", "Arial", "sans-serif"]),
+            mono: families(&["JetBrains Mono", "Fira Code", "monospace"]),
+        }
+    }
+}
+
+/// Complete set of `#rrggbb` colours for one mode.
+#[derive(Clone, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Palette {
+    /// Links, headings and highlights.
+    pub accent: String,
+    pub background: String,
+    /// Cards and other raised surfaces.
+    pub surface: String,
+    pub border: String,
+    pub text: String,
+    /// Secondary text such as periods and locations.
+    pub muted: String,
+    /// Positive figures such as added lines.
+    pub success: String,
+}
+
+impl Palette {
+    fn new([accent, background, surface, border, text, muted, success]: [&str; 7]) -> Self {
+        Palette {
+            accent: accent.to_string(),
+            background: background.to_string(),
+            surface: surface.to_string(),
+            border: border.to_string(),
+            text: text.to_string(),
+            muted: muted.to_string(),
+            success: success.to_string(),
+        }
+    }
+
+    /// Every colour with its field name.
+    pub fn colours(&self) -> [(&'static str, &str); 7] {
+        [
+            ("accent", &self.accent),
+            ("background", &self.background),
+            ("surface", &self.surface),
+            ("border", &self.border),
+            ("text", &self.text),
+            ("muted", &self.muted),
+            ("success", &self.success),
+        ]
+    }
+}
+
+/// Pull requests of the website's contributions section, gathered from
+/// every provider and merged into one list.
+#[derive(Clone, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Contributions {
+    pub providers: Vec<Provider>,
+    /// States listed; every state when empty.
+    #[serde(default)]
+    pub states: Vec<PrState>,
+    /// Number of pull requests shown, at most 100.
+    #[serde(default = "Contributions::default_count")]
+    pub count: u32,
+    #[serde(default)]
+    pub sort: PrSort,
+}
+
+impl Contributions {
+    fn default_count() -> u32 {
+        5
+    }
+}
+
+/// Code forge whose pull requests are listed.
+#[derive(Clone, Deserialize, JsonSchema)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum Provider {
+    /// github.com, searched across all repositories.
+    GitHub(GitHub),
+    /// gitlab.com or a self-hosted GitLab.
+    GitLab(Forge),
+    /// A Gitea or Forgejo instance such as Codeberg.
+    Gitea(Forge),
+}
+
+/// GitHub issue search for a user's pull requests.
+#[derive(Clone, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GitHub {
+    /// GitHub login whose pull requests are listed.
+    pub username: String,
+    /// Only pull requests to repositories of these users or organisations.
+    #[serde(default)]
+    pub orgs: Vec<String>,
+    /// Leaves out pull requests to repositories of these users or
+    /// organisations.
+    #[serde(default)]
+    pub exclude_orgs: Vec<String>,
+    /// Only pull requests to these `owner/name` repositories.
+    #[serde(default)]
+    pub repos: Vec<String>,
+    /// Leaves out pull requests to these `owner/name` repositories.
+    #[serde(default)]
+    pub exclude_repos: Vec<String>,
+    /// Also lists pull requests to the user's own repositories.
+    #[serde(default)]
+    pub own_repos: bool,
+}
+
+/// GitLab or Gitea instance, whose public APIs only filter by author within
+/// a project, so the projects are listed.
+#[derive(Clone, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Forge {
+    /// Address of the instance, e.g. `https://gitlab.com`.
+    pub url: String,
+    /// Account whose merge or pull requests are listed.
+    pub username: String,
+    /// `owner/repo` paths; GitLab subgroups nest further, e.g.
+    /// `group/subgroup/project`.
+    pub projects: Vec<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum PrState {
+    Open,
+    Merged,
+    /// Closed without being merged.
+    Closed,
+}
+
+impl PrState {
+    pub const ALL: [PrState; 3] = [PrState::Open, PrState::Merged, PrState::Closed];
+
+    /// Status the website shows for a pull request in this state.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PrState::Open => "open",
+            PrState::Merged => "merged",
+            PrState::Closed => "closed",
+        }
+    }
+}
+
+/// Order of the listed pull requests, newest or highest first, across
+/// providers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum PrSort {
+    /// By creation date.
+    #[default]
+    Created,
+    /// By last activity.
+    Updated,
+    /// By number of comments.
+    Comments,
+}
+
+impl PrSort {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PrSort::Created => "created",
+            PrSort::Updated => "updated",
+            PrSort::Comments => "comments",
+        }
+    }
+}
+
+/// Typst layouts in `crates/resume/templates`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum Template {
+    /// Single column, sections one after another.
+    #[default]
+    Classic,
+    /// Skills, education and contacts beside the experience.
+    TwoColumn,
+    /// Smaller type and spacing to fit one page.
+    Compact,
+    /// Name, contacts and skills in a coloured sidebar.
+    Modern,
+}
+
+impl Template {
+    pub const ALL: [Template; 4] = [Template::Classic, Template::TwoColumn, Template::Compact, Template::Modern];
+}
+
+/// Month-precision date written as `YYYY-MM`, stored as months since year 0
+/// so that ordering and differences are plain integer operations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct YearMonth(pub u32);
+
+impl YearMonth {
+    pub fn parse(s: &str) -> Option<Self> {
+        let (year, month) = s.split_once('-')?;
+        if year.len() != 4 || month.len() != 2 || !(year.bytes().chain(month.bytes())).all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let year: u32 = year.parse().ok()?;
+        let month: u32 = month.parse().ok()?;
+        (1..=12).contains(&month).then_some(YearMonth(year * 12 + month - 1))
+    }
+
+    /// Current month in UTC, honouring `SOURCE_DATE_EPOCH` for reproducible builds.
+    pub fn today() -> Self {
+        let secs = env::var("SOURCE_DATE_EPOCH")
+            .ok()
+            .and_then(|s| s.parse::<u64>().ok())
+            .unwrap_or_else(|| SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs()));
+        Self::from_unix(secs)
+    }
+
+    fn from_unix(secs: u64) -> Self {
+        // Days since 1970-01-01 to a civil date, after Howard Hinnant's `civil_from_days`.
+        let z = (secs / 86_400) as i64 + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + i64::from(month <= 2);
+
+        YearMonth((year * 12 + month - 1) as u32)
+    }
+
+    pub fn iso(self) -> String {
+        format!("{:04}-{:02}", self.0 / 12, self.0 % 12 + 1)
+    }
+
+    pub fn label(self, labels: &Labels) -> String {
+        format!("{} {}", labels.months[(self.0 % 12) as usize], self.0 / 12)
+    }
+}
+
+impl<'de> Deserialize<'de> for YearMonth {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        YearMonth::parse(&s)
+            .ok_or_else(|| serde::de::Error::custom(format!("invalid date `{}`, expected YYYY-MM", s)))
+    }
+}
+
+impl JsonSchema for YearMonth {
+    fn schema_name() -> Cow<'static, str> {
+        "YearMonth".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": "Month-precision date, `YYYY-MM`.",
+            "type": "string",
+            "pattern": "^[0-9]{4}-(0[1-9]|1[0-2])$"
+        })
+    }
+}
+
+impl Experience {
+    pub fn end_or_today(&self) -> YearMonth {
+        self.end.unwrap_or_else(YearMonth::today)
+    }
+
+    pub fn period(&self, labels: &Labels) -> String {
+        let end = self.end.map_or_else(|| labels.present.clone(), |end| end.label(labels));
+        format!("{} — {}", self.start.label(labels), end)
+    }
+
+    /// Both ends count, so a role from Nov 2024 to Nov 2024 lasted one month.
+    pub fn months(&self) -> u32 {
+        (self.end_or_today().0 + 1).saturating_sub(self.start.0)
+    }
+
+    pub fn duration(&self, labels: &Labels) -> String {
+        format_duration(self.months(), labels)
+    }
+}
+
+/// LinkedIn-style duration, e.g. `1 yr 11 mos`.
+pub fn format_duration(months: u32, labels: &Labels) -> String {
+    let plural = |n: u32, one: &str, many: &str| format!("{} {}", n, if n == 1 { one } else { many });
+    let fmt_years = |n| plural(n, &labels.year, &labels.years);
+    let fmt_months = |n| plural(n, &labels.month, &labels.month_plural);
+    match (months / 12, months % 12) {
+        (0, m) => fmt_months(m),
+        (y, 0) => fmt_years(y),
+        (y, m) => format!("{} {}", fmt_years(y), fmt_months(m)),
+    }
+}
+
+/// The given parts of an entry's detail line, such as its period and
+/// location, separated by ` | `.
+pub(crate) fn details(parts: &[Option<&str>]) -> String {
+    parts.iter().flatten().copied().collect::<Vec<_>>().join(" | ")
+}
+
+/// Whole years covered by at least one role; overlapping roles count once.
+pub fn years_of_experience(experience: &[Experience]) -> u32 {
+    let mut spans: Vec<(u32, u32)> = experience.iter().map(|e| (e.start.0, e.end_or_today().0)).collect();
+    spans.sort();
+
+    let mut months = 0;
+    let mut covered_until = None;
+    for (start, end) in spans {
+        let start = covered_until.map_or(start, |c: u32| start.max(c + 1));
+        if end >= start {
+            months += end - start + 1;
+        }
+        covered_until = Some(covered_until.map_or(end, |c| c.max(end)));
+    }
+    months / 12
+}
+
+impl Resume {
+    /// Copy holding only what `variant` selects; `None` keeps everything.
+    pub fn select(&self, variant: Option<&Variant>) -> Resume {
+        let selection = variant.map(|v| v.tags.as_slice());
+        let keep = |tags: &[String]| tags.is_empty() || selection.is_none_or(|sel| tags.iter().any(|t| sel.contains(t)));
+
+        let mut resume = self.clone();
+        resume.profile.summary = Summary::Plain(self.profile.summary.pick(selection).to_string());
+        resume.skills.retain(|group| keep(&group.tags));
+        for group in &mut resume.skills {
+            group.items.retain(|item| keep(item.tags()));
+        }
+        resume.skills.retain(|group| !group.items.is_empty());
+        for exp in &mut resume.experience {
+            exp.highlights.retain(|h| keep(h.tags()));
+        }
+        resume
+    }
+}
+
+/// One rendered resume: a locale combined with one of its variants.
+pub struct Edition {
+    pub locale: String,
+    pub variant: String,
+    pub resume: Resume,
+}
+
+impl Edition {
+    pub fn pdf_name(&self) -> String {
+        if self.variant == FULL_VARIANT {
+            format!("resume.{}.pdf", self.locale)
+        } else {
+            format!("resume.{}.{}.pdf", self.locale, self.variant)
+        }
+    }
+}
+
+/// Every locale in its complete form followed by each variant. The first
+/// locale is the default one; translations without variants, a `pdf` or a
+/// `theme` block of their own reuse its ones.
+pub fn editions(locales: &[(String, Resume)]) -> Vec<Edition> {
+    let Some((_, default)) = locales.first() else {
+        return Vec::new();
+    };
+    locales
+        .iter()
+        .flat_map(|(code, resume)| {
+            let variants = if resume.variants.is_empty() { &default.variants } else { &resume.variants };
+            std::iter::once(None).chain(variants.iter().map(Some)).map(move |variant| {
+                let mut resume = resume.select(variant);
+                resume.pdf = resume.pdf.or_else(|| default.pdf.clone());
+                resume.theme = resume.theme.or_else(|| default.theme.clone());
+                resume.contributions = resume.contributions.or_else(|| default.contributions.clone());
+                Edition { locale: code.clone(), variant: variant.map_or(FULL_VARIANT, |v| &v.id).to_string(), resume }
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn role(start: &str, end: Option<&str>) -> Experience {
+        Experience {
+            company: "Acme".to_string(),
+            position: "Engineer".to_string(),
+            start: YearMonth::parse(start).unwrap(),
+            end: end.map(|end| YearMonth::parse(end).unwrap()),
+            location: Some("Remote".to_string()),
+            highlights: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn parses_year_month() {
+        assert_eq!(YearMonth::parse("2024-11").map(YearMonth::iso).as_deref(), Some("2024-11"));
+        assert_eq!(YearMonth::parse("2024-13"), None);
+        assert_eq!(YearMonth::parse("2024-1"), None);
+        assert_eq!(YearMonth::parse("24-11"), None);
+        assert_eq!(YearMonth::parse("2024/11"), None);
+    }
+
+    #[test]
+    fn converts_unix_time() {
+        assert_eq!(YearMonth::from_unix(0).iso(), "1970-01");
+        assert_eq!(YearMonth::from_unix(1_709_251_199).iso(), "2024-02");
+        assert_eq!(YearMonth::from_unix(1_709_251_200).iso(), "2024-03");
+    }
+
+    #[test]
+    fn labels_periods() {
+        let labels = Labels::default();
+        assert_eq!(role("2019-09", Some("2021-07")).period(&labels), "Sep 2019 — Jul 2021");
+        assert_eq!(role("2019-09", None).period(&labels), "Sep 2019 — Present");
+    }
+
+    #[test]
+    fn formats_durations() {
+        let labels = Labels::default();
+        assert_eq!(format_duration(1, &labels), "1 mo");
+        assert_eq!(format_duration(11, &labels), "11 mos");
+        assert_eq!(format_duration(12, &labels), "1 yr");
+        assert_eq!(format_duration(23, &labels), "1 yr 11 mos");
+        assert_eq!(format_duration(25, &labels), "2 yrs 1 mo");
+        assert_eq!(role("2024-11", Some("2024-11")).duration(&labels), "1 mo");
+    }
+
+    #[test]
+    fn counts_overlapping_years_once() {
+        let experience = [
+            role("2015-01", Some("2017-12")),
+            role("2017-06", Some("2019-12")),
+            role("2021-01", Some("2021-12")),
+        ];
+        assert_eq!(years_of_experience(&experience), 6);
+    }
+
+    #[test]
+    fn picks_summary_alternatives() {
+        let summary = Summary::Alternatives(vec![
+            Tagged::Tagged { text: "Rust".to_string(), tags: vec!["rust".to_string()] },
+            Tagged::Plain("General".to_string()),
+        ]);
+        assert_eq!(summary.text(), "General");
+        assert_eq!(summary.pick(Some(&["rust".to_string()])), "Rust");
+        assert_eq!(summary.pick(Some(&["devops".to_string()])), "General");
+    }
+
+    #[test]
+    fn names_pdfs_per_edition() {
+        let resume = |variant: &str| Edition {
+            locale: "uk".to_string(),
+            variant: variant.to_string(),
+            resume: Resume {
+                labels: Labels::default(),
+                variants: Vec::new(),
+                profile: Profile { name: String::new(), title: String::new(), summary: Summary::Plain(String::new()), url: None },
+                skills: Vec::new(),
+                experience: Vec::new(),
+                education: Vec::new(),
+                contacts: Vec::new(),
+                pdf: None,
+                theme: None,
+                contributions: None,
+            },
+        };
+        assert_eq!(resume(FULL_VARIANT).pdf_name(), "resume.uk.pdf");
+        assert_eq!(resume("rust").pdf_name(), "resume.uk.rust.pdf");
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+//! Plain-text resume, for pasting into forms and emails.
+
+use crate::model::details;
+use crate::{Edition, Error};
+use std::fmt::Write;
+
+pub fn document(edition: &Edition) -> Result<String, Error> {
+    let resume = &edition.resume;
+    let labels = &resume.labels;
+    let mut out = String::new();
+
+    writeln!(out, "{}", resume.profile.name.to_uppercase())?;
+    writeln!(out, "{}", resume.profile.title)?;
+    writeln!(out)?;
+    writeln!(out, "{}", resume.profile.summary.text())?;
+
+    section_heading(&mut out, &labels.competencies)?;
+    for group in &resume.skills {
+        let items: Vec<&str> = group.items.iter().map(|item| item.text()).collect();
+        writeln!(out, "{}: {}", group.pdf_label(), items.join(", "))?;
+    }
+
+    section_heading(&mut out, &labels.experience)?;
+    for (i, exp) in resume.experience.iter().enumerate() {
+        if i > 0 {
+            writeln!(out)?;
+        }
+        writeln!(out, "{} — {}", exp.company, exp.position)?;
+        let when = format!("{} ({})", exp.period(labels), exp.duration(labels));
+        writeln!(out, "{}", details(&[Some(when.as_str()), exp.location.as_deref()]))?;
+        for highlight in &exp.highlights {
+            writeln!(out, "  - {}", highlight.text())?;
+        }
+    }
+
+    if !resume.education.is_empty() {
+        section_heading(&mut out, &labels.education)?;
+        for (i, edu) in resume.education.iter().enumerate() {
+            if i > 0 {
+                writeln!(out)?;
+            }
+            match &edu.field {
+                Some(field) => writeln!(out, "{} — {}, {}", edu.institution, edu.degree, field)?,
+                None => writeln!(out, "{} — {}", edu.institution, edu.degree)?,
+            }
+            let details = details(&[edu.period.as_deref(), edu.location.as_deref()]);
+            if !details.is_empty() {
+                writeln!(out, "{}", details)?;
+            }
+            for note in &edu.notes {
+                writeln!(out, "  - {}", note)?;
+            }
+        }
+    }
+
+    section_heading(&mut out, &labels.contacts)?;
+    for contact in &resume.contacts {
+        writeln!(out, "{}: {} <{}>", contact.platform, contact.label, contact.url)?;
+    }
+
+    Ok(out)
+}
+
+fn section_heading(out: &mut String, title: &str) -> std::fmt::Result {
+    writeln!(out)?;
+    writeln!(out, "{}", title.to_uppercase())?;
+    writeln!(out)
+}
@@ -0,0 +1,479 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+//! Printable resume and social preview card, rendered from static Typst
+//! templates fed the edition as JSON data.
+
+use crate::css::is_generic_family;
+use crate::model::details;
+use crate::validate::parse_length;
+use crate::{Edition, Error, Template};
+use ::typst::diag::{FileError, FileResult, SourceDiagnostic};
+use ::typst::eval::Tracer;
+use ::typst::foundations::{Bytes, Datetime, Dict, IntoValue, Smart};
+use ::typst::model::Document;
+use ::typst::syntax::{FileId, Source, VirtualPath};
+use ::typst::text::{Font, FontBook};
+use ::typst::visualize::Color;
+use ::typst::{Library, World};
+use comemo::Prehashed;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Family of the bundled font files, the fallback of every template.
+const FONT: &str = "DejaVu Sans";
+
+/// DejaVu Sans in the four styles the documents use, covering Latin and
+/// Cyrillic, so PDFs look the same whatever fonts the machine has.
+const FONT_FILES: [&[u8]; 4] = [
+    include_bytes!("../fonts/DejaVuSans.ttf"),
+    include_bytes!("../fonts/DejaVuSans-Bold.ttf"),
+    include_bytes!("../fonts/DejaVuSans-Oblique.ttf"),
+    include_bytes!("../fonts/DejaVuSans-BoldOblique.ttf"),
+];
+
+/// Typst source of `template`; content arrives through [`input`].
+pub fn template(template: Template) -> &'static str {
+    match template {
+        Template::Classic => include_str!("../templates/classic.typ"),
+        Template::TwoColumn => include_str!("../templates/two-column.typ"),
+        Template::Compact => include_str!("../templates/compact.typ"),
+        Template::Modern => include_str!("../templates/modern.typ"),
+    }
+}
+
+/// Typst source of the social preview card; content arrives through
+/// [`card_input`].
+pub const CARD_TEMPLATE: &str = include_str!("../templates/card.typ");
+
+/// Skills on the card, the first of each group.
+const CARD_SKILLS: usize = 6;
+
+/// Virtual path the template reads the data from, passed as `sys.inputs.data`.
+const DATA_PATH: &str = "/resume.json";
+
+/// Content of one edition as the template reads it, with periods and
+/// durations already formatted.
+#[derive(Serialize)]
+struct Input<'a> {
+    lang: &'a str,
+    style: InputStyle<'a>,
+    labels: InputLabels<'a>,
+    name: &'a str,
+    title: &'a str,
+    summary: &'a str,
+    skills: Vec<InputSkills<'a>>,
+    experience: Vec<InputExperience<'a>>,
+    education: Vec<InputEducation<'a>>,
+    contacts: Vec<InputContact<'a>>,
+}
+
+/// `pdf` overrides, falling back to the `theme` block; `null` leaves the
+/// template's default.
+#[derive(Serialize)]
+struct InputStyle<'a> {
+    /// Families in order of preference, ending with the bundled one.
+    font: Vec<&'a str>,
+    accent: Option<&'a str>,
+    text: Option<&'a str>,
+    /// In points.
+    margin: Option<f64>,
+}
+
+#[derive(Serialize)]
+struct InputLabels<'a> {
+    competencies: &'a str,
+    experience: &'a str,
+    education: &'a str,
+    contacts: &'a str,
+}
+
+#[derive(Serialize)]
+struct InputSkills<'a> {
+    label: &'a str,
+    items: Vec<&'a str>,
+}
+
+#[derive(Serialize)]
+struct InputExperience<'a> {
+    company: &'a str,
+    position: &'a str,
+    period: String,
+    duration: String,
+    location: Option<&'a str>,
+    highlights: Vec<&'a str>,
+}
+
+#[derive(Serialize)]
+struct InputEducation<'a> {
+    institution: &'a str,
+    /// Degree and field of study.
+    degree: String,
+    /// Period and location.
+    details: String,
+    notes: &'a [String],
+}
+
+#[derive(Serialize)]
+struct InputContact<'a> {
+    platform: &'a str,
+    url: &'a str,
+    label: &'a str,
+}
+
+/// Content of the social preview card.
+#[derive(Serialize)]
+struct CardInput<'a> {
+    lang: &'a str,
+    font: Vec<&'a str>,
+    colours: BTreeMap<&'static str, &'a str>,
+    name: &'a str,
+    title: &'a str,
+    skills: Vec<&'a str>,
+    /// Site address without the scheme, if the profile has one.
+    site: Option<&'a str>,
+}
+
+/// JSON data the templates render for `edition`, pretty-printed.
+pub fn input(edition: &Edition) -> Result<String, Error> {
+    let resume = &edition.resume;
+    let labels = &resume.labels;
+    let pdf = resume.pdf.clone().unwrap_or_default();
+    let theme = resume.theme.as_ref();
+    let theme_fonts = theme.into_iter().flat_map(|t| &t.font).filter(|f| !is_generic_family(f));
+    let input = Input {
+        lang: &edition.locale,
+        style: InputStyle {
+            font: pdf.font.as_deref().into_iter().chain(theme_fonts.map(String::as_str)).chain([FONT]).collect(),
+            accent: pdf.accent.as_deref().or(theme.map(|t| t.light.accent.as_str())),
+            text: pdf.text.as_deref().or(theme.map(|t| t.light.text.as_str())),
+            margin: pdf.margin.as_deref().and_then(parse_length),
+        },
+        labels: InputLabels {
+            competencies: &labels.competencies,
+            experience: &labels.experience,
+            education: &labels.education,
+            contacts: &labels.contacts,
+        },
+        name: &resume.profile.name,
+        title: &resume.profile.title,
+        summary: resume.profile.summary.text(),
+        skills: resume
+            .skills
+            .iter()
+            .map(|group| InputSkills { label: group.pdf_label(), items: group.items.iter().map(|s| s.text()).collect() })
+            .collect(),
+        experience: resume
+            .experience
+            .iter()
+            .map(|exp| InputExperience {
+                company: &exp.company,
+                position: &exp.position,
+                period: exp.period(labels),
+                duration: exp.duration(labels),
+                location: exp.location.as_deref(),
+                highlights: exp.highlights.iter().map(|h| h.text()).collect(),
+            })
+            .collect(),
+        education: resume
+            .education
+            .iter()
+            .map(|edu| InputEducation {
+                institution: &edu.institution,
+                degree: match &edu.field {
+                    Some(field) => format!("{}, {}", edu.degree, field),
+                    None => edu.degree.clone(),
+                },
+                details: details(&[edu.period.as_deref(), edu.location.as_deref()]),
+                notes: &edu.notes,
+            })
+            .collect(),
+        contacts: resume
+            .contacts
+            .iter()
+            .map(|contact| InputContact { platform: &contact.platform, url: &contact.url, label: &contact.label })
+            .collect(),
+    };
+    Ok(serde_json::to_string_pretty(&input)? + "\n")
+}
+
+/// Renders `edition` through its template into PDF bytes in-process, with
+/// only the bundled fonts and the `pdf.fonts` files, read relative to `dir`,
+/// available. The PDF carries no creation date, so equal input gives
+/// identical output.
+pub fn pdf(edition: &Edition, dir: &Path) -> Result<Vec<u8>, Error> {
+    let options = edition.resume.pdf.clone().unwrap_or_default();
+    let document = compile(template(options.template), input(edition)?, read_fonts(edition, dir)?)?;
+    Ok(typst_pdf::pdf(&document, Smart::Auto, None))
+}
+
+/// JSON data the card template renders for `edition`, in the website's dark
+/// palette and fonts, pretty-printed.
+pub fn card_input(edition: &Edition) -> Result<String, Error> {
+    let resume = &edition.resume;
+    let theme = resume.theme.clone().unwrap_or_default();
+    let input = CardInput {
+        lang: &edition.locale,
+        font: theme.font.iter().map(String::as_str).filter(|f| !is_generic_family(f)).chain([FONT]).collect(),
+        colours: theme.dark.colours().into_iter().collect(),
+        name: &resume.profile.name,
+        title: &resume.profile.title,
+        skills: resume.skills.iter().filter_map(|group| group.items.first()).map(|item| item.text()).take(CARD_SKILLS).collect(),
+        site: resume.profile.url.as_deref().map(|url| {
+            let url = url.split_once("://").map_or(url, |(_, rest)| rest);
+            url.trim_end_matches('/')
+        }),
+    };
+    Ok(serde_json::to_string_pretty(&input)? + "\n")
+}
+
+/// Renders the social preview card of `edition` into a 1200×630 PNG, with
+/// the same fonts as [`pdf`]. Equal input gives identical output.
+pub fn card(edition: &Edition, dir: &Path) -> Result<Vec<u8>, Error> {
+    let document = compile(CARD_TEMPLATE, card_input(edition)?, read_fonts(edition, dir)?)?;
+    let page = document.pages.first().ok_or_else(|| Error::Typst("card has no pages".to_string()))?;
+    typst_render::render(&page.frame, 1.0, Color::WHITE)
+        .encode_png()
+        .map_err(|e| Error::Typst(format!("failed to encode PNG: {}", e)))
+}
+
+/// Fonts of the `pdf.fonts` files, read relative to `dir`.
+fn read_fonts(edition: &Edition, dir: &Path) -> Result<Vec<Font>, Error> {
+    let mut fonts = Vec::new();
+    for file in edition.resume.pdf.iter().flat_map(|pdf| &pdf.fonts) {
+        let path = dir.join(file);
+        let data = fs::read(&path).map_err(|e| Error::io(&path, e))?;
+        let count = fonts.len();
+        fonts.extend(Font::iter(Bytes::from(data)));
+        if fonts.len() == count {
+            return Err(Error::Typst(format!("no fonts found in {}", path.display())));
+        }
+    }
+    Ok(fonts)
+}
+
+fn compile(template: &str, input: String, fonts: Vec<Font>) -> Result<Document, Error> {
+    let world = ResumeWorld::new(template, input, fonts);
+    let mut tracer = Tracer::new();
+    ::typst::compile(&world, &mut tracer).map_err(|errors| Error::Typst(describe(&errors)))
+}
+
+fn describe(diagnostics: &[SourceDiagnostic]) -> String {
+    let messages: Vec<String> = diagnostics
+        .iter()
+        .map(|d| {
+            let hints = d.hints.iter().map(|hint| format!(" (hint: {})", hint));
+            std::iter::once(d.message.to_string()).chain(hints).collect()
+        })
+        .collect();
+    messages.join("; ")
+}
+
+/// Compilation environment holding the template and its JSON data in
+/// memory, with no file system or package access.
+struct ResumeWorld {
+    library: Prehashed<Library>,
+    book: Prehashed<FontBook>,
+    fonts: Vec<Font>,
+    main: Source,
+    data: Bytes,
+}
+
+impl ResumeWorld {
+    fn new(template: &str, input: String, extra_fonts: Vec<Font>) -> Self {
+        let bundled = FONT_FILES.iter().flat_map(|data| Font::iter(Bytes::from_static(data)));
+        let fonts: Vec<Font> = bundled.chain(extra_fonts).collect();
+        let mut inputs = Dict::new();
+        inputs.insert("data".into(), DATA_PATH.into_value());
+        ResumeWorld {
+            library: Prehashed::new(Library::builder().with_inputs(inputs).build()),
+            book: Prehashed::new(FontBook::from_fonts(&fonts)),
+            fonts,
+            main: Source::new(FileId::new(None, VirtualPath::new("resume.typ")), template.to_string()),
+            data: Bytes::from(input.into_bytes()),
+        }
+    }
+}
+
+impl World for ResumeWorld {
+    fn library(&self) -> &Prehashed<Library> {
+        &self.library
+    }
+
+    fn book(&self) -> &Prehashed<FontBook> {
+        &self.book
+    }
+
+    fn main(&self) -> Source {
+        self.main.clone()
+    }
+
+    fn source(&self, id: FileId) -> FileResult<Source> {
+        if id == self.main.id() {
+            Ok(self.main.clone())
+        } else {
+            Err(FileError::NotFound(id.vpath().as_rootless_path().into()))
+        }
+    }
+
+    fn file(&self, id: FileId) -> FileResult<Bytes> {
+        if id == FileId::new(None, VirtualPath::new(DATA_PATH)) {
+            return Ok(self.data.clone());
+        }
+        Err(FileError::NotFound(id.vpath().as_rootless_path().into()))
+    }
+
+    fn font(&self, index: usize) -> Option<Font> {
+        self.fonts.get(index).cloned()
+    }
+
+    fn today(&self, _offset: Option<i64>) -> Option<Datetime> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{load, Pdf};
+    use ::typst::introspection::Meta;
+    use ::typst::layout::{Frame, FrameItem};
+    use ::typst::model::Destination;
+
+    /// Typst markup, shorthands and comments that must come out verbatim.
+    const HOSTILE: &str = r#"= *b* _i_ `c` <l> @r #x $m$ [a] \ "q" ~ -- ... // z /* w"#;
+
+    fn hostile(field: &str) -> String {
+        format!("{} {}", field, HOSTILE)
+    }
+
+    fn squash(s: &str) -> String {
+        s.split_whitespace().collect()
+    }
+
+    /// Resume with every free-text field made of [`HOSTILE`] markup.
+    fn hostile_edition() -> Edition {
+        let quoted = |field: &str| serde_json::to_string(&hostile(field)).unwrap();
+        let source = format!(
+            "\
+labels: {{ competencies: {}, experience: {}, education: {}, contacts: {} }}
+profile: {{ name: {}, title: {}, summary: {} }}
+skills:
+  - {{ id: s, label: {}, items: [{}, {}] }}
+experience:
+  - {{ company: {}, position: {}, start: 2019-09, end: 2021-06, location: {}, highlights: [{}] }}
+education:
+  - {{ institution: {}, degree: {}, field: {}, period: 2015 — 2019, location: {}, notes: [{}] }}
+contacts:
+  - {{ platform: {}, url: 'https://example.com/\")[x]#panic(\"', label: {} }}
+",
+            quoted("competencies"),
+            quoted("experience"),
+            quoted("education"),
+            quoted("contacts"),
+            quoted("name"),
+            quoted("title"),
+            quoted("summary"),
+            quoted("skills"),
+            quoted("skill one"),
+            quoted("skill two"),
+            quoted("company"),
+            quoted("position"),
+            quoted("location"),
+            quoted("highlight"),
+            quoted("institution"),
+            quoted("degree"),
+            quoted("field"),
+            quoted("campus"),
+            quoted("note"),
+            quoted("platform"),
+            quoted("label"),
+        );
+        let resume = load("resume.yaml", &source).unwrap().resume;
+        Edition { locale: "en".to_string(), variant: "full".to_string(), resume }
+    }
+
+    /// Text of every glyph run and the target of every link in `frame`.
+    fn collect(frame: &Frame, text: &mut String, links: &mut Vec<String>) {
+        for (_, item) in frame.items() {
+            match item {
+                FrameItem::Group(group) => collect(&group.frame, text, links),
+                FrameItem::Text(run) => text.push_str(&run.text),
+                FrameItem::Meta(Meta::Link(Destination::Url(url)), _) => links.push(url.to_string()),
+                _ => {}
+            }
+        }
+    }
+
+    #[test]
+    fn renders_hostile_strings_verbatim() {
+        let edition = hostile_edition();
+        for layout in Template::ALL {
+            let document = compile(template(layout), input(&edition).unwrap(), Vec::new()).unwrap();
+            let (mut text, mut links) = (String::new(), Vec::new());
+            for page in &document.pages {
+                collect(&page.frame, &mut text, &mut links);
+            }
+
+            // Long lines wrap, so compare without whitespace.
+            let text = squash(&text);
+            let fields = [
+                "name", "title", "summary", "skills", "skill one", "skill two", "company", "position", "location",
+                "highlight", "institution", "degree", "field", "campus", "note", "platform", "label",
+            ];
+            for field in fields {
+                assert!(text.contains(&squash(&hostile(field))), "{:?}: {} is not verbatim in {:?}", layout, field, text);
+            }
+            for field in ["competencies", "experience", "education", "contacts"] {
+                assert!(text.contains(&squash(&hostile(field).to_uppercase())), "{:?}: {} is not verbatim", layout, field);
+            }
+            // A label wrapping over two lines is two link areas.
+            links.dedup();
+            assert_eq!(links, ["https://example.com/\")[x]#panic(\""], "{:?}", layout);
+        }
+    }
+
+    #[test]
+    fn leaves_out_missing_locations_and_periods() {
+        let mut edition = hostile_edition();
+        edition.resume.experience[0].location = None;
+        edition.resume.education[0].period = None;
+        for layout in Template::ALL {
+            let document = compile(template(layout), input(&edition).unwrap(), Vec::new()).unwrap();
+            let (mut text, mut links) = (String::new(), Vec::new());
+            for page in &document.pages {
+                collect(&page.frame, &mut text, &mut links);
+            }
+            let text = squash(&text);
+            assert!(!text.contains("2015"), "{:?}", layout);
+            assert!(text.contains(&squash(&hostile("campus"))), "{:?}", layout);
+        }
+    }
+
+    #[test]
+    fn reads_font_files() {
+        let mut edition = hostile_edition();
+        let pdf_options = Pdf { fonts: vec!["fonts/DejaVuSans.ttf".to_string()], ..Pdf::default() };
+        edition.resume.pdf = Some(pdf_options);
+        assert!(pdf(&edition, Path::new(env!("CARGO_MANIFEST_DIR"))).is_ok());
+        match pdf(&edition, Path::new("/nonexistent")) {
+            Err(Error::Io { path, .. }) => assert_eq!(path, Path::new("/nonexistent/fonts/DejaVuSans.ttf")),
+            _ => panic!("expected an I/O error"),
+        }
+    }
+
+    #[test]
+    fn compiles_pdfs() {
+        let pdf = pdf(&hostile_edition(), Path::new(".")).unwrap();
+        assert!(pdf.starts_with(b"%PDF-"));
+    }
+
+    #[test]
+    fn reports_compile_errors() {
+        match compile("#unknown", String::new(), Vec::new()) {
+            Err(Error::Typst(message)) => assert_eq!(message, "unknown variable: unknown"),
+            _ => panic!("expected a typst error"),
+        }
+    }
+}
@@ -0,0 +1,398 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+use crate::diagnostics::Diagnostics;
+use crate::{format_duration, Experience, Forge, GitHub, Labels, Provider, Resume, Summary, FULL_VARIANT};
+use std::collections::HashSet;
+
+const URL_SCHEMES: [&str; 4] = ["http", "https", "mailto", "tel"];
+
+pub(crate) fn validate(resume: &Resume, d: &mut Diagnostics) {
+    let profile = &resume.profile;
+    d.require("profile.name", &profile.name);
+    d.require("profile.title", &profile.title);
+    match &profile.summary {
+        Summary::Plain(text) => d.require("profile.summary", text),
+        Summary::Alternatives(alternatives) if alternatives.is_empty() => d.error("profile.summary", "must not be empty"),
+        Summary::Alternatives(alternatives) => {
+            for (i, alternative) in alternatives.iter().enumerate() {
+                d.require(&format!("profile.summary[{}]", i), alternative.text());
+            }
+        }
+    }
+    if let Some(url) = &profile.url {
+        match url::Url::parse(url) {
+            Ok(parsed) if matches!(parsed.scheme(), "http" | "https") => {}
+            _ => d.error("profile.url", format!("invalid URL `{}`, expected an absolute http(s) address", url)),
+        }
+    }
+
+    if resume.labels.months.len() != 12 {
+        d.error("labels.months", format!("expected 12 month names, found {}", resume.labels.months.len()));
+    }
+
+    if resume.skills.is_empty() {
+        d.warn("skills", "no skill groups listed");
+    }
+    let mut ids = HashSet::new();
+    for (i, group) in resume.skills.iter().enumerate() {
+        let path = format!("skills[{}]", i);
+        d.require(&format!("{}.id", path), &group.id);
+        d.require(&format!("{}.label", path), &group.label);
+        if !ids.insert(group.id.as_str()) {
+            d.error(&format!("{}.id", path), format!("duplicate skill group id `{}`", group.id));
+        }
+        if group.items.is_empty() {
+            d.warn(&format!("{}.items", path), format!("skill group `{}` has no items", group.id));
+        }
+    }
+
+    if resume.experience.is_empty() {
+        d.warn("experience", "no experience entries listed");
+    }
+    for (i, exp) in resume.experience.iter().enumerate() {
+        let path = format!("experience[{}]", i);
+        d.require(&format!("{}.company", path), &exp.company);
+        d.require(&format!("{}.position", path), &exp.position);
+        if let Some(location) = &exp.location {
+            d.require(&format!("{}.location", path), location);
+        }
+        if exp.end.is_some_and(|end| end < exp.start) {
+            d.error(&format!("{}.end", path), "ends before it starts");
+        }
+        if exp.highlights.is_empty() {
+            d.warn(&format!("{}.highlights", path), format!("`{}` has no highlights", exp.company));
+        }
+    }
+
+    for (i, edu) in resume.education.iter().enumerate() {
+        let path = format!("education[{}]", i);
+        d.require(&format!("{}.institution", path), &edu.institution);
+        d.require(&format!("{}.degree", path), &edu.degree);
+        if let Some(period) = &edu.period {
+            d.check_period(&format!("{}.period", path), period, &resume.labels);
+        }
+    }
+
+    if resume.contacts.is_empty() {
+        d.warn("contacts", "no contacts listed");
+    }
+    for (i, contact) in resume.contacts.iter().enumerate() {
+        let path = format!("contacts[{}]", i);
+        d.require(&format!("{}.platform", path), &contact.platform);
+        d.require(&format!("{}.label", path), &contact.label);
+        match url::Url::parse(&contact.url) {
+            Ok(url) if URL_SCHEMES.contains(&url.scheme()) => {}
+            Ok(url) => d.error(
+                &format!("{}.url", path),
+                format!("unsupported URL scheme `{}`, expected one of {}", url.scheme(), URL_SCHEMES.join(", ")),
+            ),
+            Err(e) => d.error(&format!("{}.url", path), format!("invalid URL `{}`: {}", contact.url, e)),
+        }
+    }
+
+    if let Some(pdf) = &resume.pdf {
+        if let Some(font) = &pdf.font {
+            d.require("pdf.font", font);
+        } else if !pdf.fonts.is_empty() {
+            d.warn("pdf.fonts", "font files are listed but `pdf.font` does not select them");
+        }
+        for (path, colour) in [("pdf.accent", &pdf.accent), ("pdf.text", &pdf.text)] {
+            if let Some(colour) = colour {
+                check_colour(path, colour, d);
+            }
+        }
+        if let Some(margin) = pdf.margin.as_ref().filter(|m| parse_length(m).is_none()) {
+            d.error("pdf.margin", format!("invalid length `{}`, expected e.g. `1.5cm`, `12mm`, `0.5in` or `36pt`", margin));
+        }
+    }
+
+    if let Some(theme) = &resume.theme {
+        for (mode, palette) in [("dark", &theme.dark), ("light", &theme.light)] {
+            for (name, colour) in palette.colours() {
+                check_colour(&format!("theme.{}.{}", mode, name), colour, d);
+            }
+        }
+        for (path, families) in [("theme.font", &theme.font), ("theme.mono", &theme.mono)] {
+            if families.is_empty() {
+                d.error(path, "must list at least one font family");
+            }
+            // Families end up in the generated stylesheet.
+            for (i, family) in families.iter().enumerate() {
+                if family.is_empty() || family.contains(|c: char| c.is_control() || "\"'\\;{}<>".contains(c)) {
+                    d.error(&format!("{}[{}]", path, i), format!("invalid font family `{}`", family));
+                }
+            }
+        }
+    }
+
+    if let Some(contributions) = &resume.contributions {
+        if contributions.providers.is_empty() {
+            d.warn("contributions.providers", "no providers listed");
+        }
+        for (i, provider) in contributions.providers.iter().enumerate() {
+            let path = format!("contributions.providers[{}]", i);
+            match provider {
+                Provider::GitHub(github) => check_github(&path, github, d),
+                Provider::GitLab(forge) => check_forge(&path, forge, true, d),
+                Provider::Gitea(forge) => check_forge(&path, forge, false, d),
+            }
+        }
+        if !(1..=100).contains(&contributions.count) {
+            d.error("contributions.count", format!("expected 1 to 100 pull requests, found {}", contributions.count));
+        }
+    }
+}
+
+// Names end up in request URLs.
+fn check_github(path: &str, github: &GitHub, d: &mut Diagnostics) {
+    if !is_login(&github.username) {
+        d.error(&format!("{}.username", path), format!("invalid GitHub login `{}`", github.username));
+    }
+    for (field, orgs) in [("orgs", &github.orgs), ("exclude_orgs", &github.exclude_orgs)] {
+        for (i, org) in orgs.iter().enumerate().filter(|(_, org)| !is_login(org)) {
+            d.error(&format!("{}.{}[{}]", path, field, i), format!("invalid GitHub user or organisation `{}`", org));
+        }
+    }
+    for (field, repos) in [("repos", &github.repos), ("exclude_repos", &github.exclude_repos)] {
+        for (i, repo) in repos.iter().enumerate().filter(|(_, repo)| !is_repo(repo)) {
+            d.error(&format!("{}.{}[{}]", path, field, i), format!("invalid repository `{}`, expected `owner/name`", repo));
+        }
+    }
+}
+
+/// Only GitLab nests projects in subgroups.
+fn check_forge(path: &str, forge: &Forge, nested: bool, d: &mut Diagnostics) {
+    match url::Url::parse(&forge.url) {
+        Ok(parsed) if matches!(parsed.scheme(), "http" | "https") && parsed.query().is_none() => {}
+        _ => d.error(&format!("{}.url", path), format!("invalid URL `{}`, expected an absolute http(s) address", forge.url)),
+    }
+    if !is_username(&forge.username) {
+        d.error(&format!("{}.username", path), format!("invalid username `{}`", forge.username));
+    }
+    if forge.projects.is_empty() {
+        d.warn(&format!("{}.projects", path), "no projects listed");
+    }
+    for (i, project) in forge.projects.iter().enumerate() {
+        let segments: Vec<&str> = project.split('/').collect();
+        let depth_ok = if nested { segments.len() >= 2 } else { segments.len() == 2 };
+        if !depth_ok || !segments.iter().all(|segment| is_username(segment)) {
+            let expected = if nested { "group/project" } else { "owner/repo" };
+            d.error(&format!("{}.projects[{}]", path, i), format!("invalid project `{}`, expected `{}`", project, expected));
+        }
+    }
+}
+
+fn check_colour(path: &str, colour: &str, d: &mut Diagnostics) {
+    if !is_colour(colour) {
+        d.error(path, format!("invalid colour `{}`, expected `#rrggbb`", colour));
+    }
+}
+
+/// Variant ids must be unique URL-friendly slugs; tags that only one side
+/// mentions are most likely typos.
+pub(crate) fn check_variants(resume: &Resume, d: &mut Diagnostics) {
+    let mut used: Vec<(String, &str)> = Vec::new();
+    if let Summary::Alternatives(alternatives) = &resume.profile.summary {
+        for (i, alternative) in alternatives.iter().enumerate() {
+            used.extend(alternative.tags().iter().map(|t| (format!("profile.summary[{}].tags", i), t.as_str())));
+        }
+    }
+    for (i, group) in resume.skills.iter().enumerate() {
+        used.extend(group.tags.iter().map(|t| (format!("skills[{}].tags", i), t.as_str())));
+        for (j, item) in group.items.iter().enumerate() {
+            used.extend(item.tags().iter().map(|t| (format!("skills[{}].items[{}].tags", i, j), t.as_str())));
+        }
+    }
+    for (i, exp) in resume.experience.iter().enumerate() {
+        for (j, highlight) in exp.highlights.iter().enumerate() {
+            used.extend(highlight.tags().iter().map(|t| (format!("experience[{}].highlights[{}].tags", i, j), t.as_str())));
+        }
+    }
+
+    let mut ids = HashSet::new();
+    for (i, variant) in resume.variants.iter().enumerate() {
+        let path = format!("variants[{}]", i);
+        let valid = !variant.id.is_empty() && variant.id.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+        if !valid {
+            d.error(&format!("{}.id", path), format!("`{}` must be a lowercase slug such as `devops`", variant.id));
+        } else if variant.id == FULL_VARIANT {
+            d.error(&format!("{}.id", path), format!("`{}` is reserved for the complete resume", FULL_VARIANT));
+        } else if !ids.insert(variant.id.as_str()) {
+            d.error(&format!("{}.id", path), format!("duplicate variant id `{}`", variant.id));
+        }
+        for tag in &variant.tags {
+            if !used.iter().any(|(_, t)| t == tag) {
+                d.warn(&format!("{}.tags", path), format!("tag `{}` is not used by any entry", tag));
+            }
+        }
+    }
+
+    if !resume.variants.is_empty() {
+        for (path, tag) in &used {
+            if !resume.variants.iter().any(|v| v.tags.iter().any(|t| t == tag)) {
+                d.warn(path, format!("tag `{}` is not selected by any variant", tag));
+            }
+        }
+    }
+}
+
+/// Warns about months not covered by any role and about roles overlapping by
+/// more than the shared month of a job switch.
+pub(crate) fn check_timeline(experience: &[Experience], d: &mut Diagnostics) {
+    let mut order: Vec<usize> = (0..experience.len()).collect();
+    order.sort_by_key(|&i| (experience[i].start, experience[i].end_or_today()));
+
+    for pair in order.windows(2) {
+        let (older, newer) = (&experience[pair[0]], &experience[pair[1]]);
+        let older_end = older.end_or_today();
+        let path = format!("experience[{}].start", pair[1]);
+
+        if newer.start.0 > older_end.0 + 1 {
+            d.warn(
+                &path,
+                format!(
+                    "{} gap between `{}` and `{}`",
+                    format_duration(newer.start.0 - older_end.0 - 1, &Labels::default()),
+                    older.company,
+                    newer.company
+                ),
+            );
+        } else if newer.start < older_end {
+            d.warn(
+                &path,
+                format!(
+                    "`{}` overlaps `{}` by {}",
+                    newer.company,
+                    older.company,
+                    format_duration(older_end.0 - newer.start.0 + 1, &Labels::default())
+                ),
+            );
+        }
+    }
+}
+
+/// Accepts `YYYY` or `Mon YYYY`, optionally followed by ` — ` and an end
+/// date or `Present`, using the month names and wording of the resume's labels.
+pub(crate) fn is_valid_period(period: &str, labels: &Labels) -> bool {
+    let mut parts = period.split(" — ");
+    let start = parts.next().unwrap_or_default();
+    let end = parts.next();
+
+    parts.next().is_none()
+        && parse_month_year(start, labels).is_some()
+        && end.is_none_or(|end| end == labels.present || parse_month_year(end, labels).is_some())
+}
+
+/// ISO 8601 start and end (`YYYY` or `YYYY-MM`) of a validated period; an
+/// open end yields `None`.
+pub(crate) fn period_bounds(period: &str, labels: &Labels) -> (Option<String>, Option<String>) {
+    let mut parts = period.split(" — ");
+    let start = parts.next().and_then(|s| parse_month_year(s, labels));
+    let end = parts.next().and_then(|s| parse_month_year(s, labels));
+    let end = if period.contains(" — ") { end } else { start.clone() };
+    (start, end)
+}
+
+/// Parses `YYYY` or `Mon YYYY` into `YYYY` or `YYYY-MM`.
+fn parse_month_year(s: &str, labels: &Labels) -> Option<String> {
+    let (month, year) = match s.split_once(' ') {
+        Some((month, year)) => (Some(labels.months.iter().position(|m| m == month)?), year),
+        None => (None, s),
+    };
+    if year.len() != 4 || !year.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    Some(match month {
+        Some(month) => format!("{}-{:02}", year, month + 1),
+        None => year.to_string(),
+    })
+}
+
+/// GitHub user or organisation name.
+fn is_login(s: &str) -> bool {
+    !s.is_empty() && !s.starts_with('-') && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+}
+
+/// GitLab and Gitea names, which also allow dots and underscores.
+fn is_username(s: &str) -> bool {
+    !s.is_empty() && !s.starts_with(['-', '.']) && s.chars().all(|c| c.is_ascii_alphanumeric() || "-_.".contains(c))
+}
+
+fn is_repo(s: &str) -> bool {
+    s.split_once('/').is_some_and(|(owner, name)| {
+        is_login(owner) && !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || "-_.".contains(c))
+    })
+}
+
+/// `#rrggbb` hex colour.
+fn is_colour(s: &str) -> bool {
+    s.len() == 7 && s.starts_with('#') && s[1..].bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+/// Non-negative length in `pt`, `mm`, `cm` or `in`, converted to points.
+pub(crate) fn parse_length(s: &str) -> Option<f64> {
+    let unit = s.trim_start_matches(|c: char| c.is_ascii_digit() || c == '.');
+    let points_per_unit = match unit {
+        "pt" => 1.0,
+        "mm" => 72.0 / 25.4,
+        "cm" => 72.0 / 2.54,
+        "in" => 72.0,
+        _ => return None,
+    };
+    let value: f64 = s[..s.len() - unit.len()].parse().ok()?;
+    Some(value * points_per_unit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_periods() {
+        let labels = Labels::default();
+        assert!(is_valid_period("2008 — 2013", &labels));
+        assert!(is_valid_period("Sep 2019 — Present", &labels));
+        assert!(is_valid_period("2020", &labels));
+        assert!(!is_valid_period("2008 - 2013", &labels));
+        assert!(!is_valid_period("Sept 2019 — Present", &labels));
+        assert!(!is_valid_period("2008 — 2013 — 2015", &labels));
+    }
+
+    #[test]
+    fn bounds_periods() {
+        let labels = Labels::default();
+        assert_eq!(period_bounds("Sep 2019 — Jun 2021", &labels), (Some("2019-09".to_string()), Some("2021-06".to_string())));
+        assert_eq!(period_bounds("2008 — Present", &labels), (Some("2008".to_string()), None));
+        assert_eq!(period_bounds("2020", &labels), (Some("2020".to_string()), Some("2020".to_string())));
+    }
+
+    #[test]
+    fn parses_lengths() {
+        assert_eq!(parse_length("36pt"), Some(36.0));
+        assert_eq!(parse_length("0.5in"), Some(36.0));
+        assert!((parse_length("1.5cm").unwrap() - 42.52).abs() < 0.01);
+        assert_eq!(parse_length("1.5"), None);
+        assert_eq!(parse_length("-1cm"), None);
+        assert_eq!(parse_length("cm"), None);
+    }
+
+    #[test]
+    fn accepts_colours() {
+        assert!(is_colour("#0066cc"));
+        assert!(!is_colour("#06c"));
+        assert!(!is_colour("0066cc"));
+        assert!(!is_colour("#0066cg"));
+    }
+
+    #[test]
+    fn accepts_github_names() {
+        assert!(is_login("nikita-voronoy"));
+        assert!(!is_login("-jane"));
+        assert!(!is_login("jane doe"));
+        assert!(is_repo("rust-lang/rust.vim"));
+        assert!(!is_repo("rust-lang"));
+        assert!(!is_repo("rust-lang/rust repo:other/x"));
+        assert!(is_username("jane.doe_1"));
+        assert!(!is_username(".."));
+    }
+}
@@ -0,0 +1,33 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+// Social preview card shown for shared links, rendered to a 1200×630 PNG in
+// the website's colours.
+
+#let data = json(sys.inputs.data)
+#let colours = data.colours
+#let accent = rgb(colours.accent)
+
+#set page(width: 1200pt, height: 630pt, margin: (x: 88pt, y: 72pt), fill: rgb(colours.background))
+#set text(font: data.font, fill: rgb(colours.text), lang: data.lang)
+
+#place(top + left, dx: -88pt, dy: -72pt, rect(width: 20pt, height: 630pt, fill: accent))
+
+#text(size: 72pt, weight: "bold", data.name)
+#v(4pt)
+#text(size: 32pt, fill: rgb(colours.muted), data.title)
+
+#v(1fr)
+#for skill in data.skills {
+  box(
+    fill: rgb(colours.surface),
+    stroke: 2pt + rgb(colours.border),
+    radius: 12pt,
+    inset: (x: 20pt, y: 12pt),
+    text(size: 24pt, skill),
+  )
+  h(14pt)
+}
+#if data.site != none {
+  v(32pt)
+  text(size: 24pt, weight: "bold", fill: accent, data.site)
+}
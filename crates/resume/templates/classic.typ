@@ -0,0 +1,56 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+// Classic layout: one column, sections one after another.
+
+#let data = json(sys.inputs.data)
+#let labels = data.labels
+#let style = data.style
+#let accent = rgb(if style.accent == none { "#0066cc" } else { style.accent })
+#let ink = rgb(if style.text == none { "#000000" } else { style.text })
+
+#set page(margin: if style.margin == none { 1.5cm } else { style.margin * 1pt })
+#set text(font: style.font, size: 9pt, fill: ink, lang: data.lang)
+#show link: it => underline(text(fill: accent, it))
+
+#let section(title) = {
+  text(size: 12pt, weight: "bold", upper(title))
+  parbreak()
+}
+
+#align(center, text(size: 18pt, weight: "bold", data.name))
+#align(center, text(size: 11pt, data.title))
+
+#data.summary
+
+#section(labels.competencies)
+#for group in data.skills {
+  strong(group.label + ":")
+  [ ]
+  group.items.join(", ")
+  parbreak()
+}
+
+#section(labels.experience)
+#for job in data.experience {
+  text(size: 10pt, weight: "bold", job.company + " — " + job.position)
+  [ ]
+  text(style: "italic", job.period + " (" + job.duration + ")" + if job.location != none { " | " + job.location })
+  list(..job.highlights)
+}
+
+#if data.education.len() > 0 {
+  section(labels.education)
+  for school in data.education {
+    text(size: 10pt, weight: "bold", school.institution + " — " + school.degree)
+    [ ]
+    text(style: "italic", school.details)
+    list(..school.notes)
+  }
+}
+
+#section(labels.contacts)
+#for contact in data.contacts {
+  contact.platform + ": "
+  link(contact.url, contact.label)
+  parbreak()
+}
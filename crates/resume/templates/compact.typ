@@ -0,0 +1,59 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+// Compact layout: smaller type, tight spacing and one-line entry headers,
+// meant to fit a typical resume on one page.
+
+#let data = json(sys.inputs.data)
+#let labels = data.labels
+#let style = data.style
+#let accent = rgb(if style.accent == none { "#0066cc" } else { style.accent })
+#let ink = rgb(if style.text == none { "#000000" } else { style.text })
+
+#set page(margin: if style.margin == none { 1cm } else { style.margin * 1pt })
+#set text(font: style.font, size: 8pt, fill: ink, lang: data.lang)
+#set par(leading: 0.45em)
+#set block(spacing: 0.55em)
+#set list(tight: true, indent: 0.5em, spacing: 0.35em)
+#show link: it => text(fill: accent, it)
+
+#let section(title) = block(above: 0.9em, below: 0.45em, text(size: 9pt, weight: "bold", fill: accent, upper(title)))
+
+#text(size: 14pt, weight: "bold", data.name)
+#h(0.6em)
+#text(size: 10pt, data.title)
+
+#data.summary
+
+#section(labels.competencies)
+#for group in data.skills {
+  block(strong(group.label + ": ") + group.items.join(", "))
+}
+
+#section(labels.experience)
+#for job in data.experience {
+  block(below: 0.3em, {
+    strong(job.company + " — " + job.position)
+    h(1fr)
+    text(style: "italic", job.period + " (" + job.duration + ")" + if job.location != none { " | " + job.location })
+  })
+  list(..job.highlights)
+}
+
+#if data.education.len() > 0 {
+  section(labels.education)
+  for school in data.education {
+    block(below: 0.3em, {
+      strong(school.institution + " — " + school.degree)
+      h(1fr)
+      text(style: "italic", school.details)
+    })
+    list(..school.notes)
+  }
+}
+
+#section(labels.contacts)
+#for (i, contact) in data.contacts.enumerate() {
+  if i > 0 { [ · ] }
+  contact.platform + ": "
+  link(contact.url, contact.label)
+}
@@ -0,0 +1,75 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+// Modern layout: name, contacts, skills and education in a tinted sidebar
+// running the full page height, summary and experience beside it.
+
+#let data = json(sys.inputs.data)
+#let labels = data.labels
+#let style = data.style
+#let accent = rgb(if style.accent == none { "#2b4c7e" } else { style.accent })
+#let ink = rgb(if style.text == none { "#1a1a1a" } else { style.text })
+#let margin = if style.margin == none { 1.2cm } else { style.margin * 1pt }
+#let sidebar = 5.6cm
+
+#set page(
+  margin: (left: sidebar + margin, rest: margin),
+  background: place(left, rect(width: sidebar, height: 100%, fill: accent.lighten(88%))),
+)
+#set text(font: style.font, size: 9pt, fill: ink, lang: data.lang)
+#show link: it => text(fill: accent, it)
+
+#let section(title) = block(above: 1.2em, below: 0.6em, text(size: 10.5pt, weight: "bold", fill: accent, upper(title)))
+
+// The sidebar sits in the left margin of every page but only holds content on
+// the first one.
+#place(
+  top + left,
+  dx: -sidebar - margin + 0.6cm,
+  block(width: sidebar - 1.2cm, {
+    text(size: 16pt, weight: "bold", fill: accent, data.name)
+    parbreak()
+    text(size: 10pt, data.title)
+
+    section(labels.contacts)
+    for contact in data.contacts {
+      block(below: 0.6em, text(size: 8pt, contact.platform) + linebreak() + link(contact.url, contact.label))
+    }
+
+    section(labels.competencies)
+    for group in data.skills {
+      block(below: 0.8em, strong(group.label) + linebreak() + group.items.join(", "))
+    }
+
+    if data.education.len() > 0 {
+      section(labels.education)
+      for school in data.education {
+        block(below: 0.8em, {
+          strong(school.institution)
+          linebreak()
+          school.degree
+          linebreak()
+          text(style: "italic", school.details)
+          list(..school.notes)
+        })
+      }
+    }
+  }),
+)
+
+#data.summary
+
+#section(labels.experience)
+#for job in data.experience {
+  block(below: 1em, {
+    text(size: 10.5pt, weight: "bold", job.position)
+    linebreak()
+    text(fill: accent, weight: "bold", job.company)
+    h(1fr)
+    text(style: "italic", job.period + " (" + job.duration + ")")
+    if job.location != none {
+      linebreak()
+      text(size: 8pt, job.location)
+    }
+    list(..job.highlights)
+  })
+}
@@ -0,0 +1,74 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+// Two-column layout: summary and experience on the right, skills, education
+// and contacts in a narrower column on the left.
+
+#let data = json(sys.inputs.data)
+#let labels = data.labels
+#let style = data.style
+#let accent = rgb(if style.accent == none { "#1f5f8b" } else { style.accent })
+#let ink = rgb(if style.text == none { "#222222" } else { style.text })
+
+#set page(margin: if style.margin == none { 1.5cm } else { style.margin * 1pt })
+#set text(font: style.font, size: 9pt, fill: ink, lang: data.lang)
+#show link: it => text(fill: accent, it)
+
+#let section(title) = block(above: 1.2em, below: 0.6em, width: 100%, stroke: (bottom: 0.5pt + accent), inset: (bottom: 3pt))[
+  #text(size: 10.5pt, weight: "bold", fill: accent, upper(title))
+]
+
+#block(below: 1em)[
+  #text(size: 20pt, weight: "bold", data.name) \
+  #text(size: 11pt, fill: accent, data.title)
+]
+
+#grid(
+  columns: (1fr, 2.2fr),
+  column-gutter: 1.2em,
+  {
+    section(labels.competencies)
+    for group in data.skills {
+      block(below: 0.8em, strong(group.label) + linebreak() + group.items.join(", "))
+    }
+
+    if data.education.len() > 0 {
+      section(labels.education)
+      for school in data.education {
+        block(below: 0.8em, {
+          strong(school.institution)
+          linebreak()
+          school.degree
+          linebreak()
+          text(style: "italic", school.details)
+          list(..school.notes)
+        })
+      }
+    }
+
+    section(labels.contacts)
+    for contact in data.contacts {
+      block(below: 0.6em, text(size: 8pt, contact.platform) + linebreak() + link(contact.url, contact.label))
+    }
+  },
+  {
+    block(above: 1.2em, data.summary)
+
+    section(labels.experience)
+    for job in data.experience {
+      block(below: 0.9em, {
+        text(size: 10pt, weight: "bold", job.position)
+        h(1fr)
+        text(style: "italic", job.period)
+        linebreak()
+        text(fill: accent, job.company)
+        [ · ]
+        if job.location != none {
+          job.location
+          [ · ]
+        }
+        job.duration
+        list(..job.highlights)
+      })
+    }
+  },
+)
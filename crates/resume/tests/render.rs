@@ -0,0 +1,96 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+//! Snapshot tests of every renderer against `fixtures/resume.yaml`, whose
+//! roles have all ended so that durations do not depend on the current date.
+
+use resume::{codegen, css, editions, html, json_resume, load, markdown, text, typst, Edition, Template};
+use std::path::Path;
+
+fn fixture_editions() -> Vec<Edition> {
+    let loaded = load("resume.yaml", include_str!("fixtures/resume.yaml")).expect("fixture is valid");
+    editions(&[("en".to_string(), loaded.resume)])
+}
+
+#[test]
+fn fixture_loads_with_expected_warnings() {
+    let loaded = load("resume.yaml", include_str!("fixtures/resume.yaml")).unwrap();
+    let warnings: Vec<String> = loaded.warnings.iter().map(ToString::to_string).collect();
+    insta::assert_debug_snapshot!(warnings);
+}
+
+#[test]
+fn rust_code() {
+    insta::assert_snapshot!(codegen::rust_code(&fixture_editions()).unwrap());
+}
+
+#[test]
+fn site_json() {
+    insta::assert_snapshot!(codegen::json(&fixture_editions()).unwrap());
+}
+
+#[test]
+fn typst_full() {
+    insta::assert_snapshot!(typst::input(&fixture_editions()[0]).unwrap());
+}
+
+#[test]
+fn typst_variant() {
+    insta::assert_snapshot!(typst::input(&fixture_editions()[1]).unwrap());
+}
+
+#[test]
+fn pdf_is_reproducible() {
+    for mut edition in fixture_editions() {
+        for template in Template::ALL {
+            edition.resume.pdf.as_mut().unwrap().template = template;
+            let pdf = typst::pdf(&edition, Path::new(".")).unwrap();
+            assert!(pdf.starts_with(b"%PDF-"));
+            assert_eq!(pdf, typst::pdf(&edition, Path::new(".")).unwrap());
+        }
+    }
+}
+
+#[test]
+fn typst_card() {
+    insta::assert_snapshot!(typst::card_input(&fixture_editions()[0]).unwrap());
+}
+
+#[test]
+fn card_is_reproducible_png() {
+    let edition = &fixture_editions()[0];
+    let png = typst::card(edition, Path::new(".")).unwrap();
+    assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+    let dimension = |at: usize| u32::from_be_bytes(png[at..at + 4].try_into().unwrap());
+    assert_eq!((dimension(16), dimension(20)), (1200, 630));
+    assert_eq!(png, typst::card(edition, Path::new(".")).unwrap());
+}
+
+#[test]
+fn json_resume() {
+    insta::assert_snapshot!(json_resume::export(&fixture_editions()[0], "1.2.3").unwrap());
+}
+
+#[test]
+fn markdown() {
+    insta::assert_snapshot!(markdown::document(&fixture_editions()[0]).unwrap());
+}
+
+#[test]
+fn text() {
+    insta::assert_snapshot!(text::document(&fixture_editions()[0]).unwrap());
+}
+
+#[test]
+fn theme_css() {
+    insta::assert_snapshot!(css::theme(fixture_editions()[0].resume.theme.as_ref().unwrap()).unwrap());
+}
+
+#[test]
+fn head() {
+    insta::assert_snapshot!(html::head(&fixture_editions()[0]).unwrap());
+}
+
+#[test]
+fn schema() {
+    insta::assert_snapshot!(resume::schema().unwrap());
+}
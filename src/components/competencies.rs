@@ -1,10 +1,8 @@
 // Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
 
 use leptos::prelude::*;
-use crate::data::{
-    SKILLS_CLOUD, SKILLS_DEVOPS, SKILLS_MONITORING,
-    SKILLS_LANGUAGES, SKILLS_RUST, SKILLS_DB, SKILLS_SECURITY,
-};
+use crate::context::resume_or_context;
+use crate::data::Resume;
 
 #[component]
 fn SkillGroup(label: &'static str, skills: &'static [&'static str]) -> impl IntoView {
@@ -21,18 +19,16 @@ fn SkillGroup(label: &'static str, skills: &'static [&'static str]) -> impl Into
 }
 
 #[component]
-pub fn Competencies() -> impl IntoView {
+pub fn Competencies(#[prop(optional, into)] resume: Option<Signal<&'static Resume>>) -> impl IntoView {
+    let resume = resume_or_context(resume);
+
     view! {
         <section class="section">
-            <h2 class="section-title">"Core Competencies"</h2>
+            <h2 class="section-title">{move || resume.get().labels.competencies}</h2>
             <div class="competencies">
-                <SkillGroup label="Cloud & Infrastructure" skills=SKILLS_CLOUD />
-                <SkillGroup label="DevOps & Automation" skills=SKILLS_DEVOPS />
-                <SkillGroup label="Monitoring" skills=SKILLS_MONITORING />
-                <SkillGroup label="Languages" skills=SKILLS_LANGUAGES />
-                <SkillGroup label="Rust Ecosystem" skills=SKILLS_RUST />
-                <SkillGroup label="Databases" skills=SKILLS_DB />
-                <SkillGroup label="Security" skills=SKILLS_SECURITY />
+                {move || resume.get().skill_groups.iter().map(|group| view! {
+                    <SkillGroup label=group.label skills=group.items />
+                }).collect_view()}
             </div>
         </section>
     }
@@ -1,189 +1,282 @@
 // Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
 
 use leptos::prelude::*;
-use gloo_net::http::Request;
+use leptos::task::spawn_local;
+use gloo_net::http::{Request, Response};
 use gloo_storage::{LocalStorage, Storage};
 use serde::{Deserialize, Serialize};
-use crate::data::BUILD_TIMESTAMP;
+use crate::context::resume_or_context;
+use crate::data::{
+    ContributionSearch, ContributionSource, Resume, BUILD_TIMESTAMP, CONTRIBUTIONS, CONTRIBUTIONS_SNAPSHOT,
+};
+use crate::providers::{merge, provider, PullRequest};
 
-const GITHUB_USERNAME: &str = "nikita-voronoy";
 const CACHE_KEY: &str = "contributions_cache";
+/// How long a fetched list is shown without refreshing it.
+const CACHE_TTL_MS: f64 = 60.0 * 60.0 * 1000.0;
+/// Prefix of the keys, one per forge, of the `Date.now()` time before which
+/// requesting again would only be refused.
+const BACKOFF_KEY: &str = "contributions_backoff";
+/// Wait after a refusal that does not say how long to wait.
+const DEFAULT_BACKOFF_MS: f64 = 60.0 * 1000.0;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 struct CacheData {
     version: String,
+    /// `Date.now()` of the fetch.
+    fetched_at: f64,
+    ttl: f64,
     items: Vec<PullRequest>,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
-struct GithubSearchResponse {
-    items: Vec<GithubItem>,
+impl CacheData {
+    /// Lists cached by another build may come from a different search.
+    fn is_stale(&self) -> bool {
+        self.version != BUILD_TIMESTAMP || js_sys::Date::now() - self.fetched_at >= self.ttl
+    }
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
-struct GithubItem {
-    title: String,
-    html_url: String,
-    state: String,
-    created_at: String,
-    pull_request: Option<PullRequestInfo>,
-    repository_url: String,
+/// What the section shows.
+#[derive(Clone)]
+enum Listing {
+    Loading,
+    Loaded(Vec<PullRequest>),
+    /// Rate-limited with nothing cached to show instead, until this
+    /// `Date.now()` time.
+    Limited(f64),
+    /// The fetch failed with nothing cached to show instead.
+    Failed,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
-struct PullRequestInfo {
-    merged_at: Option<String>,
+enum FetchError {
+    /// The forge refuses requests until this `Date.now()` time.
+    Limited(f64),
+    /// Logged where it happened.
+    Failed,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
-struct PullRequest {
-    title: String,
-    url: String,
-    repo: String,
-    repo_url: String,
-    status: String,
-    date: String,
-}
-
-fn extract_repo_name(repo_url: &str) -> String {
-    repo_url
-        .strip_prefix("https://api.github.com/repos/")
-        .unwrap_or(repo_url)
-        .to_string()
-}
-
-fn format_date(iso_date: &str) -> String {
-    let date_part = iso_date.split('T').next().unwrap_or(iso_date);
-    let parts: Vec<&str> = date_part.split('-').collect();
-    if parts.len() == 3 {
-        let month = match parts[1] {
-            "01" => "Jan",
-            "02" => "Feb",
-            "03" => "Mar",
-            "04" => "Apr",
-            "05" => "May",
-            "06" => "Jun",
-            "07" => "Jul",
-            "08" => "Aug",
-            "09" => "Sep",
-            "10" => "Oct",
-            "11" => "Nov",
-            "12" => "Dec",
-            _ => parts[1],
-        };
-        let day = parts[2].trim_start_matches('0');
-        format!("{} {}, {}", month, day, parts[0])
-    } else {
-        date_part.to_string()
-    }
-}
-
-fn get_status(item: &GithubItem) -> String {
-    if let Some(pr) = &item.pull_request {
-        if pr.merged_at.is_some() {
-            return "merged".to_string();
-        }
-    }
-    item.state.clone()
-}
-
-fn load_from_cache() -> Option<Vec<PullRequest>> {
-    let cached: Result<CacheData, _> = LocalStorage::get(CACHE_KEY);
-    cached.ok().and_then(|data| {
-        if data.version == BUILD_TIMESTAMP {
-            Some(data.items)
-        } else {
-            None
-        }
-    })
+fn load_from_cache() -> Option<CacheData> {
+    LocalStorage::get(CACHE_KEY).ok()
 }
 
 fn save_to_cache(items: &[PullRequest]) {
     let data = CacheData {
         version: BUILD_TIMESTAMP.to_string(),
+        fetched_at: js_sys::Date::now(),
+        ttl: CACHE_TTL_MS,
         items: items.to_vec(),
     };
     let _ = LocalStorage::set(CACHE_KEY, data);
 }
 
-async fn fetch_or_cache() -> Result<Vec<PullRequest>, String> {
-    if let Some(cached) = load_from_cache() {
-        return Ok(cached);
+/// When the forge accepts requests again, if `response` says it will not
+/// now: `Retry-After` seconds for secondary limits, the reset Unix time
+/// once the quota is used up, which GitHub sends as `X-RateLimit-Reset` and
+/// GitLab as `RateLimit-Reset`.
+fn limited_until(response: &Response) -> Option<f64> {
+    let headers = response.headers();
+    let header = |name| headers.get(name).and_then(|value| value.trim().parse::<f64>().ok());
+    let either = |github, gitlab| header(github).or_else(|| header(gitlab));
+    let now = js_sys::Date::now();
+    let refused = matches!(response.status(), 403 | 429);
+
+    if let Some(seconds) = header("retry-after").filter(|_| refused) {
+        Some(now + seconds * 1000.0)
+    } else if either("x-ratelimit-remaining", "ratelimit-remaining") == Some(0.0) {
+        let reset = either("x-ratelimit-reset", "ratelimit-reset");
+        Some(reset.map_or(now + DEFAULT_BACKOFF_MS, |reset| reset * 1000.0))
+    } else {
+        (response.status() == 429).then_some(now + DEFAULT_BACKOFF_MS)
+    }
+}
+
+/// Requests every source and merges the pull requests of those that
+/// answered. Fails only when none did, as rate-limited when any source was.
+async fn fetch(search: &ContributionSearch) -> Result<Vec<PullRequest>, FetchError> {
+    let mut items = Vec::new();
+    let mut answered = search.sources.is_empty();
+    let mut limited: Option<f64> = None;
+    for source in search.sources {
+        match fetch_source(source).await {
+            Ok(found) => {
+                items.extend(found);
+                answered = true;
+            }
+            Err(FetchError::Limited(until)) => limited = Some(limited.map_or(until, |earliest| earliest.min(until))),
+            Err(FetchError::Failed) => {}
+        }
+    }
+
+    if answered {
+        Ok(merge(items, search))
+    } else {
+        Err(limited.map_or(FetchError::Failed, FetchError::Limited))
     }
+}
 
-    let url = format!(
-        "https://api.github.com/search/issues?q=author:{}+type:pr+is:public+-user:{}&sort=created&order=desc&per_page=5",
-        GITHUB_USERNAME, GITHUB_USERNAME
-    );
+/// Skips the request while an earlier response from the same forge asked to
+/// back off.
+async fn fetch_source(source: &ContributionSource) -> Result<Vec<PullRequest>, FetchError> {
+    let failed = |reason: String| {
+        leptos::logging::warn!("contributions: {}: {}", source.url, reason);
+        FetchError::Failed
+    };
+    let Some(provider) = provider(source) else {
+        return Err(failed(format!("unknown provider {}", source.provider)));
+    };
+    let backoff_key = format!("{}:{}", BACKOFF_KEY, source.site);
+    let backoff: Option<f64> = LocalStorage::get(&backoff_key).ok();
+    if let Some(until) = backoff.filter(|&until| until > js_sys::Date::now()) {
+        return Err(FetchError::Limited(until));
+    }
 
-    let response = Request::get(&url)
-        .header("Accept", "application/vnd.github.v3+json")
+    let response = Request::get(source.url)
+        .header("Accept", provider.accept())
         .send()
         .await
-        .map_err(|e| e.to_string())?;
+        .map_err(|e| failed(e.to_string()))?;
 
+    // Also set when this request used up the quota but still succeeded.
+    let limited = limited_until(&response);
+    if let Some(until) = limited {
+        let _ = LocalStorage::set(&backoff_key, until);
+    }
     if !response.ok() {
-        return Err(format!("GitHub API error: {}", response.status()));
+        return Err(match limited {
+            Some(until) => FetchError::Limited(until),
+            None => failed(format!("API error: {}", response.status())),
+        });
     }
 
-    let data: GithubSearchResponse = response.json().await.map_err(|e| e.to_string())?;
-
-    let items: Vec<PullRequest> = data
-        .items
-        .into_iter()
-        .map(|item| {
-            let status = get_status(&item);
-            let repo = extract_repo_name(&item.repository_url);
-            let repo_url = format!("https://github.com/{}", repo);
-            PullRequest {
-                title: item.title,
-                url: item.html_url,
-                repo,
-                repo_url,
-                status,
-                date: format_date(&item.created_at),
+    let body = response.json().await.map_err(|e| failed(e.to_string()))?;
+    provider.pull_requests(body).map_err(|e| failed(e.to_string()))
+}
+
+/// Responses embedded at build time from `contributions.json`.
+#[derive(Deserialize)]
+struct Snapshot {
+    responses: Vec<SnapshotResponse>,
+}
+
+#[derive(Deserialize)]
+struct SnapshotResponse {
+    url: String,
+    body: serde_json::Value,
+}
+
+/// Results of the sources read from the snapshot; responses to requests the
+/// search no longer makes are skipped.
+fn snapshot(search: &ContributionSearch) -> Option<Vec<PullRequest>> {
+    let snapshot: Snapshot = serde_json::from_str(CONTRIBUTIONS_SNAPSHOT?).ok()?;
+    let mut items = Vec::new();
+    let mut matched = false;
+    for response in snapshot.responses {
+        let Some(source) = search.sources.iter().find(|source| source.url == response.url) else { continue };
+        let Some(provider) = provider(source) else { continue };
+        if let Ok(found) = provider.pull_requests(response.body) {
+            items.extend(found);
+            matched = true;
+        }
+    }
+    matched.then(|| merge(items, search))
+}
+
+/// Starts from the build-time snapshot, if any, which the pre-rendered page
+/// shows too. In the browser the cached list replaces it at once and is
+/// refreshed in the background when stale or missing; a failed refresh
+/// leaves whatever list is shown in place. Also returns a retry for when
+/// nothing could be shown.
+fn use_listing(search: &'static ContributionSearch) -> (ReadSignal<Listing>, impl Fn() + Copy) {
+    let listing = RwSignal::new(snapshot(search).map_or(Listing::Loading, Listing::Loaded));
+
+    Effect::new(move || {
+        let cached = load_from_cache();
+        let stale = cached.as_ref().is_none_or(CacheData::is_stale);
+        if let Some(cached) = cached {
+            listing.set(Listing::Loaded(cached.items));
+        }
+        if stale {
+            refresh(search, listing);
+        }
+    });
+
+    let retry = move || {
+        listing.set(Listing::Loading);
+        refresh(search, listing);
+    };
+    (listing.read_only(), retry)
+}
+
+fn refresh(search: &'static ContributionSearch, listing: RwSignal<Listing>) {
+    spawn_local(async move {
+        let failure = match fetch(search).await {
+            Ok(items) => {
+                save_to_cache(&items);
+                listing.set(Listing::Loaded(items));
+                return;
+            }
+            Err(FetchError::Limited(until)) => Listing::Limited(until),
+            Err(FetchError::Failed) => Listing::Failed,
+        };
+        listing.update(|listing| {
+            if !matches!(listing, Listing::Loaded(_)) {
+                *listing = failure;
             }
-        })
-        .collect();
+        });
+    });
+}
 
-    save_to_cache(&items);
-    Ok(items)
+/// Local `HH:MM` of a `Date.now()` time.
+fn clock(time: f64) -> String {
+    let date = js_sys::Date::new(&time.into());
+    format!("{:02}:{:02}", date.get_hours(), date.get_minutes())
 }
 
 #[component]
-pub fn Contributions() -> impl IntoView {
-    let contributions = LocalResource::new(|| fetch_or_cache());
-
-    view! {
-        <section class="section">
-            <h2 class="section-title">"Open Source Contributions"</h2>
-            <Suspense fallback=move || view! { <p class="loading">"Loading..."</p> }>
-                {move || Suspend::new(async move {
-                    match contributions.await {
-                        Ok(items) if !items.is_empty() => {
-                            view! {
-                                <div class="contributions-list">
-                                    {items.into_iter().map(|pr| {
-                                        let status_class = format!("pr-status pr-{}", pr.status);
-                                        view! {
-                                            <div class="contribution-item">
-                                                <a href={pr.url} target="_blank" class="pr-title">{pr.title}</a>
-                                                <div class="pr-meta">
-                                                    <a href={pr.repo_url} target="_blank" class="pr-repo">{pr.repo}</a>
-                                                    <span class={status_class}>{pr.status.clone()}</span>
-                                                    <span class="pr-date">{pr.date}</span>
-                                                </div>
-                                            </div>
-                                        }
-                                    }).collect_view()}
-                                </div>
-                            }.into_any()
-                        }
-                        Ok(_) => view! { <p>"No contributions found"</p> }.into_any(),
-                        Err(e) => view! { <p class="error">{e}</p> }.into_any(),
-                    }
-                })}
-            </Suspense>
-        </section>
-    }
+pub fn Contributions(#[prop(optional, into)] resume: Option<Signal<&'static Resume>>) -> impl IntoView {
+    let resume = resume_or_context(resume);
+    // Left out when `resume.yaml` has no `contributions` block.
+    let search: &'static Option<ContributionSearch> = &CONTRIBUTIONS;
+
+    search.as_ref().map(|search| {
+        let (listing, retry) = use_listing(search);
+        let retry_button = move || view! {
+            <button class="retry-btn" on:click=move |_| retry()>{resume.get().labels.retry}</button>
+        };
+
+        view! {
+            <section class="section">
+                <h2 class="section-title">{move || resume.get().labels.contributions}</h2>
+                {move || match listing.get() {
+                    Listing::Loading => view! { <p class="loading">{resume.get().labels.loading}</p> }.into_any(),
+                    Listing::Loaded(items) if !items.is_empty() => view! {
+                        <div class="contributions-list">
+                            {items.into_iter().map(|pr| {
+                                let status_class = format!("pr-status pr-{}", pr.status);
+                                view! {
+                                    <div class="contribution-item">
+                                        <a href={pr.url} target="_blank" class="pr-title">{pr.title}</a>
+                                        <div class="pr-meta">
+                                            <a href={pr.repo_url} target="_blank" class="pr-repo">{pr.repo}</a>
+                                            <span class={status_class}>{pr.status.clone()}</span>
+                                            <span class="pr-date">{pr.date}</span>
+                                        </div>
+                                    </div>
+                                }
+                            }).collect_view()}
+                        </div>
+                    }.into_any(),
+                    Listing::Loaded(_) => view! { <p>{resume.get().labels.no_contributions}</p> }.into_any(),
+                    Listing::Limited(until) => view! {
+                        <p class="error">{resume.get().labels.rate_limited}" "{clock(until)}</p>
+                        {retry_button()}
+                    }.into_any(),
+                    Listing::Failed => view! {
+                        <p class="error">{resume.get().labels.contributions_failed}</p>
+                        {retry_button()}
+                    }.into_any(),
+                }}
+            </section>
+        }
+    })
 }
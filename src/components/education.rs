@@ -0,0 +1,62 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+use leptos::prelude::*;
+use crate::context::resume_or_context;
+use crate::data::Resume;
+
+#[component]
+fn EducationItem(
+    institution: &'static str,
+    degree: &'static str,
+    field: Option<&'static str>,
+    period: Option<&'static str>,
+    location: Option<&'static str>,
+    notes: &'static [&'static str],
+) -> impl IntoView {
+    view! {
+        <div class="education-item">
+            <div class="edu-degree">
+                {degree}
+                {field.map(|f| view! { ", "{f} })}
+            </div>
+            <div class="edu-details">
+                {institution}
+                {period.map(|p| view! { " · "{p} })}
+                {location.map(|l| view! { " · "{l} })}
+            </div>
+            {(!notes.is_empty()).then(|| view! {
+                <ul class="terminal-list">
+                    {notes.iter().map(|n| view! {
+                        <li>{*n}</li>
+                    }).collect_view()}
+                </ul>
+            })}
+        </div>
+    }
+}
+
+#[component]
+pub fn Education(#[prop(optional, into)] resume: Option<Signal<&'static Resume>>) -> impl IntoView {
+    let resume = resume_or_context(resume);
+
+    move || {
+        let resume = resume.get();
+        (!resume.education.is_empty()).then(|| view! {
+            <section class="section">
+                <h2 class="section-title">{resume.labels.education}</h2>
+                <div class="education-list">
+                    {resume.education.iter().map(|edu| view! {
+                        <EducationItem
+                            institution=edu.institution
+                            degree=edu.degree
+                            field=edu.field
+                            period=edu.period
+                            location=edu.location
+                            notes=edu.notes
+                        />
+                    }).collect_view()}
+                </div>
+            </section>
+        })
+    }
+}
@@ -1,14 +1,18 @@
 // Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
 
 use leptos::prelude::*;
-use crate::data::EXPERIENCE;
+use crate::data::Labels;
+use crate::context::resume_or_context;
+use crate::data::Resume;
 
 #[component]
 fn TerminalCard(
+    labels: &'static Labels,
     company: &'static str,
     position: &'static str,
     period: &'static str,
-    location: &'static str,
+    duration: &'static str,
+    location: Option<&'static str>,
     highlights: &'static [&'static str],
 ) -> impl IntoView {
     view! {
@@ -25,8 +29,8 @@ fn TerminalCard(
                     <span class="cmd">" cat info.txt"</span>
                 </div>
                 <div class="terminal-output">
-                    <span class="output-label">"Period: "</span>{period}<br/>
-                    <span class="output-label">"Location: "</span>{location}
+                    <span class="output-label">{labels.period}": "</span>{period}" ("{duration}")"
+                    {location.map(|l| view! { <br/><span class="output-label">{labels.location}": "</span>{l} })}
                 </div>
                 <div class="terminal-line">
                     <span class="prompt">"$"</span>
@@ -43,16 +47,23 @@ fn TerminalCard(
 }
 
 #[component]
-pub fn Experience() -> impl IntoView {
+pub fn Experience(#[prop(optional, into)] resume: Option<Signal<&'static Resume>>) -> impl IntoView {
+    let resume = resume_or_context(resume);
+
     view! {
         <section class="section">
-            <h2 class="section-title">"Professional Experience"</h2>
+            <h2 class="section-title">{move || resume.get().labels.experience}</h2>
+            <span class="section-meta">
+                {move || resume.get().years_of_experience}{move || resume.get().labels.years_total}
+            </span>
             <div class="timeline">
-                {EXPERIENCE.iter().map(|exp| view! {
+                {move || resume.get().experience.iter().map(|exp| view! {
                     <TerminalCard
+                        labels=&resume.get().labels
                         company=exp.company
                         position=exp.position
                         period=exp.period
+                        duration=exp.duration
                         location=exp.location
                         highlights=exp.highlights
                     />
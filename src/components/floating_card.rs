@@ -2,7 +2,8 @@
 
 use leptos::prelude::*;
 use leptos::task::spawn_local;
-use crate::data::{PROFILE, CONTACTS, Contact};
+use crate::context::resume_or_context;
+use crate::data::{Contact, Resume};
 use crate::utils::download_pdf;
 
 fn contact_icon(contact: &Contact) -> &'static str {
@@ -16,7 +17,8 @@ fn contact_icon(contact: &Contact) -> &'static str {
 }
 
 #[component]
-pub fn FloatingCard() -> impl IntoView {
+pub fn FloatingCard(#[prop(optional, into)] resume: Option<Signal<&'static Resume>>) -> impl IntoView {
+    let resume = resume_or_context(resume);
     let (can_download, set_can_download) = signal(false);
     let (honeypot, set_honeypot) = signal(String::new());
     let (is_open, set_is_open) = signal(false);
@@ -32,8 +34,9 @@ pub fn FloatingCard() -> impl IntoView {
         if !honeypot.get().is_empty() || !can_download.get() {
             return;
         }
-        let filename = format!("{}_CV.pdf", PROFILE.name.replace(" ", "_"));
-        download_pdf(&filename);
+        let resume = resume.get();
+        let filename = format!("{}_CV.pdf", resume.profile.name.replace(" ", "_"));
+        download_pdf(resume.pdf, &filename);
     };
 
     view! {
@@ -59,14 +62,14 @@ pub fn FloatingCard() -> impl IntoView {
                 on:click=on_download
             >
                 {move || if can_download.get() {
-                    "Download Resume"
+                    resume.get().labels.download
                 } else {
-                    "Loading..."
+                    resume.get().labels.loading
                 }}
             </button>
             <div class="floating-divider"></div>
             <div class="contact-icons">
-                {CONTACTS.iter().map(|c| view! {
+                {move || resume.get().contacts.iter().map(|c| view! {
                     <a href={c.url} target="_blank" class="contact-icon-link" title={c.label}>
                         {contact_icon(c)}
                     </a>
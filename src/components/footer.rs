@@ -1,13 +1,20 @@
 // Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
 
 use leptos::prelude::*;
-use crate::data::{BUILD_VERSION, BUILD_COMMIT};
+use crate::context::use_resume_context;
 
 #[component]
-pub fn Footer() -> impl IntoView {
+pub fn Footer(
+    #[prop(optional)] version: Option<&'static str>,
+    #[prop(optional)] commit: Option<&'static str>,
+) -> impl IntoView {
+    let context = use_resume_context();
+    let version = version.unwrap_or(context.version);
+    let commit = commit.unwrap_or(context.commit);
+
     view! {
         <footer class="footer">
-            <span class="version">"v"{BUILD_VERSION}" ("{BUILD_COMMIT}")"</span>
+            <span class="version">"v"{version}" ("{commit}")"</span>
         </footer>
     }
 }
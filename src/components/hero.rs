@@ -1,15 +1,18 @@
 // Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
 
 use leptos::prelude::*;
-use crate::data::PROFILE;
+use crate::context::resume_or_context;
+use crate::data::Resume;
 
 #[component]
-pub fn Hero() -> impl IntoView {
+pub fn Hero(#[prop(optional, into)] resume: Option<Signal<&'static Resume>>) -> impl IntoView {
+    let resume = resume_or_context(resume);
+
     view! {
         <section class="hero">
-            <h1 class="name">{PROFILE.name}</h1>
-            <p class="title">{PROFILE.title}</p>
-            <p class="summary">{PROFILE.summary}</p>
+            <h1 class="name">{move || resume.get().profile.name}</h1>
+            <p class="title">{move || resume.get().profile.title}</p>
+            <p class="summary">{move || resume.get().profile.summary}</p>
         </section>
     }
 }
@@ -0,0 +1,31 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+use leptos::prelude::*;
+use crate::data::DEFAULT_VARIANT;
+use crate::locale::use_locale;
+use crate::resumes::use_resumes;
+
+#[component]
+pub fn LocaleSwitcher() -> impl IntoView {
+    let locale = use_locale();
+    let resumes = use_resumes();
+
+    move || {
+        let locales: Vec<_> = resumes.get().iter().filter(|r| r.variant == DEFAULT_VARIANT).collect();
+
+        (locales.len() > 1).then(|| view! {
+            <nav class="locale-switcher">
+                {locales.into_iter().map(|r| view! {
+                    <button
+                        class="locale-btn"
+                        class:active=move || locale.get() == r.locale
+                        title=r.labels.language
+                        on:click=move |_| locale.set(r.locale)
+                    >
+                        {r.locale.to_uppercase()}
+                    </button>
+                }).collect_view()}
+            </nav>
+        })
+    }
+}
@@ -3,13 +3,19 @@
 mod hero;
 mod competencies;
 mod experience;
+mod education;
 mod contributions;
 mod floating_card;
 mod footer;
+mod locale_switcher;
+mod theme_switcher;
 
 pub use hero::Hero;
 pub use competencies::Competencies;
 pub use experience::Experience;
+pub use education::Education;
 pub use contributions::Contributions;
 pub use floating_card::FloatingCard;
 pub use footer::Footer;
+pub use locale_switcher::LocaleSwitcher;
+pub use theme_switcher::ThemeSwitcher;
@@ -0,0 +1,45 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+use leptos::prelude::*;
+use crate::context::use_resume_context;
+use crate::theme::{use_theme_mode, ThemeMode};
+
+fn mode_icon(mode: ThemeMode) -> &'static str {
+    match mode {
+        ThemeMode::Light => "\u{2600}",
+        ThemeMode::Dark => "\u{263E}",
+        ThemeMode::System => "\u{25D0}",
+    }
+}
+
+#[component]
+pub fn ThemeSwitcher() -> impl IntoView {
+    let mode = use_theme_mode();
+    let resume = use_resume_context().resume;
+
+    let label = move |option: ThemeMode| {
+        let labels = &resume.get().labels;
+        match option {
+            ThemeMode::Light => labels.theme_light,
+            ThemeMode::Dark => labels.theme_dark,
+            ThemeMode::System => labels.theme_system,
+        }
+    };
+
+    view! {
+        <nav class="theme-switcher">
+            {ThemeMode::ALL.into_iter().map(|option| view! {
+                <button
+                    class="theme-btn"
+                    class:active=move || mode.get() == option
+                    title=move || label(option)
+                    aria-label=move || label(option)
+                    aria-pressed=move || (mode.get() == option).to_string()
+                    on:click=move |_| mode.set(option)
+                >
+                    {mode_icon(option)}
+                </button>
+            }).collect_view()}
+        </nav>
+    }
+}
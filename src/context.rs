@@ -0,0 +1,40 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+use leptos::prelude::*;
+use crate::data::{resume, Resume, BUILD_COMMIT, BUILD_VERSION};
+use crate::locale::use_locale;
+use crate::resumes::use_resumes;
+use crate::variant::use_variant;
+
+/// What the page sections render, provided once by `App`. Sections also take
+/// it piecewise as props, which win over the context.
+#[derive(Clone, Copy)]
+pub struct ResumeContext {
+    /// Resume for the selected locale and variant.
+    pub resume: Signal<&'static Resume>,
+    pub version: &'static str,
+    pub commit: &'static str,
+}
+
+/// Follows the selected locale and variant; call after `provide_locale` and
+/// `provide_variant`.
+pub fn provide_resume_context() {
+    let resumes = use_resumes();
+    let locale = use_locale();
+    let variant = use_variant();
+
+    provide_context(ResumeContext {
+        resume: Signal::derive(move || resume(resumes.get(), locale.get(), variant.get())),
+        version: BUILD_VERSION,
+        commit: BUILD_COMMIT,
+    });
+}
+
+pub fn use_resume_context() -> ResumeContext {
+    expect_context::<ResumeContext>()
+}
+
+/// The `resume` prop of a section, or the context's when none was passed.
+pub fn resume_or_context(resume: Option<Signal<&'static Resume>>) -> Signal<&'static Resume> {
+    resume.unwrap_or_else(|| use_resume_context().resume)
+}
@@ -2,3 +2,11 @@
 
 include!(concat!(env!("OUT_DIR"), "/resume_data.rs"));
 include!(concat!(env!("OUT_DIR"), "/build_info.rs"));
+include!(concat!(env!("OUT_DIR"), "/contributions.rs"));
+
+/// Resume for `locale` and `variant`, falling back to the complete resume of
+/// that locale and then to the default one from `resume.yaml`.
+pub fn resume(resumes: &'static [Resume], locale: &str, variant: &str) -> &'static Resume {
+    let find = |variant: &str| resumes.iter().find(|r| r.locale == locale && r.variant == variant);
+    find(variant).or_else(|| find(DEFAULT_VARIANT)).unwrap_or(&resumes[0])
+}
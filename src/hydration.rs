@@ -0,0 +1,27 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+use leptos::prelude::*;
+
+/// Whether this build renders the static page instead of running in the browser.
+pub const PRERENDER: bool = cfg!(feature = "ssr");
+
+/// Signal holding `prerendered`, the value the static page was rendered with,
+/// until the app is live in the browser, then `restore()` computed from
+/// browser state such as `LocalStorage` or the URL. Hydration only attaches
+/// to markup that matches, so anything the server cannot know starts out as
+/// the pre-rendered value.
+pub fn restored<T>(prerendered: T, restore: impl FnOnce() -> T) -> RwSignal<T>
+where
+    T: Send + Sync + 'static,
+{
+    let signal = RwSignal::new(prerendered);
+    if !PRERENDER {
+        let mut value = Some(restore());
+        Effect::new(move || {
+            if let Some(value) = value.take() {
+                signal.set(value);
+            }
+        });
+    }
+    signal
+}
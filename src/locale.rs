@@ -0,0 +1,71 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+use leptos::prelude::*;
+use gloo_storage::{LocalStorage, Storage};
+use crate::data::{Resume, RESUMES};
+use crate::hydration::restored;
+use crate::resumes::use_resumes;
+
+const STORAGE_KEY: &str = "locale";
+
+/// Selected locale code, provided once by `App`.
+#[derive(Clone, Copy)]
+struct Locale(RwSignal<&'static str>);
+
+/// Picks the stored locale, then the browser language, then the default one,
+/// and keeps `LocalStorage` and `<html lang>` in sync with later changes. The
+/// page is pre-rendered in the default locale. Needs the resumes context, so
+/// `App` provides that first.
+pub fn provide_locale() {
+    let resumes = use_resumes();
+    // Read before the effect below stores the locale picked from them.
+    let preferred = StoredValue::new(Vec::new());
+    let locale = restored(RESUMES[0].locale, move || {
+        preferred.set_value(preferred_locales());
+        preferred.with_value(|codes| pick_locale(codes, resumes.get_untracked()))
+    });
+
+    // Locales only `resumes.json` has are known once it loads.
+    #[cfg(feature = "runtime-data")]
+    Effect::new(move || {
+        let resumes = resumes.get();
+        locale.set(preferred.with_value(|codes| pick_locale(codes, resumes)));
+    });
+
+    Effect::new(move || {
+        let code = locale.get();
+        let _ = LocalStorage::set(STORAGE_KEY, code);
+        if let Some(root) = document().document_element() {
+            let _ = root.set_attribute("lang", code);
+        }
+    });
+
+    provide_context(Locale(locale));
+}
+
+pub fn use_locale() -> RwSignal<&'static str> {
+    expect_context::<Locale>().0
+}
+
+/// The stored locale, then the browser language.
+fn preferred_locales() -> Vec<String> {
+    let stored: Option<String> = LocalStorage::get(STORAGE_KEY).ok();
+    let browser = window().navigator().language();
+    stored.into_iter().chain(browser).collect()
+}
+
+fn pick_locale(codes: &[String], resumes: &'static [Resume]) -> &'static str {
+    codes
+        .iter()
+        .find_map(|code| match_locale(code, resumes))
+        .unwrap_or(resumes[0].locale)
+}
+
+/// Matches `uk` as well as region-qualified tags such as `uk-UA`.
+fn match_locale(code: &str, resumes: &'static [Resume]) -> Option<&'static str> {
+    let language = code.split('-').next().unwrap_or(code);
+    resumes
+        .iter()
+        .map(|r| r.locale)
+        .find(|&locale| locale.eq_ignore_ascii_case(code) || locale.eq_ignore_ascii_case(language))
+}
@@ -2,22 +2,54 @@
 
 mod data;
 mod components;
+mod context;
+mod hydration;
+mod locale;
+#[cfg(feature = "ssr")]
+mod prerender;
+mod providers;
+mod resumes;
+mod theme;
 mod utils;
+mod variant;
 
 use leptos::prelude::*;
-use components::{Hero, Competencies, Experience, Contributions, FloatingCard, Footer};
+use components::{
+    Hero, Competencies, Experience, Education, Contributions, FloatingCard, Footer, LocaleSwitcher,
+    ThemeSwitcher,
+};
 
+#[cfg(not(feature = "ssr"))]
 fn main() {
+    // `trunk serve` does not pre-render, leaving nothing to hydrate.
+    #[cfg(feature = "hydrate")]
+    if document().query_selector("body > main").ok().flatten().is_some() {
+        return leptos::mount::hydrate_body(App);
+    }
     leptos::mount::mount_to_body(App);
 }
 
+#[cfg(feature = "ssr")]
+fn main() {
+    prerender::run();
+}
+
 #[component]
 fn App() -> impl IntoView {
+    resumes::provide_resumes();
+    variant::provide_variant();
+    locale::provide_locale();
+    theme::provide_theme();
+    context::provide_resume_context();
+
     view! {
+        <ThemeSwitcher />
+        <LocaleSwitcher />
         <main class="container">
             <Hero />
             <Competencies />
             <Experience />
+            <Education />
             <Contributions />
             <FloatingCard />
         </main>
@@ -0,0 +1,52 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+//! Static pre-rendering, run natively after `trunk build`:
+//!
+//!   cargo run --no-default-features --features ssr -- dist/index.html
+//!
+//! Renders `App` into the page's `<body>` so the resume is readable without
+//! JavaScript, and replaces its `<title>` with the tags `build.rs` generated
+//! from the profile; the wasm bundle then hydrates the body.
+
+use leptos::prelude::*;
+use std::path::Path;
+use std::{env, fs, io, process};
+use crate::App;
+
+const DEFAULT_PAGE: &str = "dist/index.html";
+const HEAD: &str = include_str!(concat!(env!("OUT_DIR"), "/head.html"));
+
+pub fn run() {
+    let page = env::args().nth(1).unwrap_or_else(|| DEFAULT_PAGE.to_string());
+    if let Err(e) = prerender(Path::new(&page)) {
+        eprintln!("{}: {}", page, e);
+        process::exit(1);
+    }
+}
+
+fn prerender(page: &Path) -> io::Result<()> {
+    let html = fs::read_to_string(page)?;
+    let body = Owner::new().with(|| App().to_html());
+    let html = fill_body(&html, &body)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "no <body> element"))?;
+    let html = replace_title(&html, HEAD)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "no <title> element"))?;
+    fs::write(page, html)
+}
+
+/// Puts `tags` in place of the `<title>` element, keeping its indentation.
+fn replace_title(html: &str, tags: &str) -> Option<String> {
+    let start = html.find("<title")?;
+    let end = start + html[start..].find("</title>")? + "</title>".len();
+    let indent = &html[html[..start].rfind('\n').map_or(0, |i| i + 1)..start];
+    let tags = tags.trim_end().replace('\n', &format!("\n{}", indent));
+    Some(format!("{}{}{}", &html[..start], tags, &html[end..]))
+}
+
+/// Replaces the contents of `<body>`.
+fn fill_body(html: &str, body: &str) -> Option<String> {
+    let open = html.find("<body")?;
+    let content = open + html[open..].find('>')? + 1;
+    let close = html.rfind("</body>").filter(|&close| close >= content)?;
+    Some(format!("{}{}{}", &html[..content], body, &html[close..]))
+}
@@ -0,0 +1,99 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+use serde::Deserialize;
+use serde_json::Value;
+use super::{format_date, ContributionProvider, PullRequest};
+
+/// Pull requests of one repository on Gitea or Forgejo, e.g. Codeberg.
+pub struct Gitea {
+    /// Web address of the instance, without a trailing slash.
+    pub site: &'static str,
+}
+
+/// Gitea lists pull requests as issues.
+#[derive(Deserialize)]
+struct Issue {
+    title: String,
+    html_url: String,
+    state: String,
+    created_at: String,
+    updated_at: String,
+    comments: u32,
+    pull_request: Option<PullRequestMeta>,
+    repository: Repository,
+}
+
+#[derive(Deserialize)]
+struct PullRequestMeta {
+    merged: bool,
+}
+
+#[derive(Deserialize)]
+struct Repository {
+    full_name: String,
+}
+
+impl ContributionProvider for Gitea {
+    fn pull_requests(&self, body: Value) -> serde_json::Result<Vec<PullRequest>> {
+        let issues: Vec<Issue> = serde_json::from_value(body)?;
+        Ok(issues
+            .into_iter()
+            .map(|issue| {
+                // Merged pull requests are closed ones.
+                let merged = issue.pull_request.is_some_and(|pr| pr.merged);
+                PullRequest {
+                    title: issue.title,
+                    url: issue.html_url,
+                    repo_url: format!("{}/{}", self.site, issue.repository.full_name),
+                    repo: issue.repository.full_name,
+                    status: if merged { "merged".to_string() } else { issue.state },
+                    date: format_date(&issue.created_at),
+                    created_at: issue.created_at,
+                    updated_at: issue.updated_at,
+                    comments: issue.comments,
+                }
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn issue(state: &str, pull_request: Value) -> Value {
+        json!({
+            "title": "Docs",
+            "html_url": "https://codeberg.org/jane/notes/pulls/1",
+            "state": state,
+            "created_at": "2024-03-01T13:00:00+01:00",
+            "updated_at": "2024-03-01T13:00:00+01:00",
+            "comments": 2,
+            "pull_request": pull_request,
+            "repository": { "full_name": "jane/notes" },
+        })
+    }
+
+    #[test]
+    fn tells_merged_from_closed() {
+        let gitea = Gitea { site: "https://codeberg.org" };
+        let body = json!([
+            issue("open", json!({ "merged": false })),
+            issue("closed", json!({ "merged": true })),
+            issue("closed", json!({ "merged": false })),
+            issue("closed", Value::Null),
+        ]);
+        let statuses: Vec<_> = gitea.pull_requests(body).unwrap().into_iter().map(|pr| pr.status).collect();
+        assert_eq!(statuses, ["open", "merged", "closed", "closed"]);
+    }
+
+    #[test]
+    fn links_the_repository() {
+        let gitea = Gitea { site: "https://codeberg.org" };
+        let pr = gitea.pull_requests(json!([issue("open", Value::Null)])).unwrap().remove(0);
+        assert_eq!(pr.repo, "jane/notes");
+        assert_eq!(pr.repo_url, "https://codeberg.org/jane/notes");
+        assert_eq!(pr.comments, 2);
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+use serde::Deserialize;
+use serde_json::Value;
+use super::{format_date, ContributionProvider, PullRequest};
+
+/// GitHub's issue search, which lists the pull requests of every repository
+/// in one request.
+pub struct GitHub;
+
+#[derive(Deserialize)]
+struct SearchResponse {
+    items: Vec<Item>,
+}
+
+#[derive(Deserialize)]
+struct Item {
+    title: String,
+    html_url: String,
+    state: String,
+    created_at: String,
+    updated_at: String,
+    comments: u32,
+    pull_request: Option<PullRequestInfo>,
+    repository_url: String,
+}
+
+#[derive(Deserialize)]
+struct PullRequestInfo {
+    merged_at: Option<String>,
+}
+
+fn extract_repo_name(repo_url: &str) -> String {
+    repo_url
+        .strip_prefix("https://api.github.com/repos/")
+        .unwrap_or(repo_url)
+        .to_string()
+}
+
+fn get_status(item: &Item) -> String {
+    if let Some(pr) = &item.pull_request {
+        if pr.merged_at.is_some() {
+            return "merged".to_string();
+        }
+    }
+    item.state.clone()
+}
+
+impl ContributionProvider for GitHub {
+    fn accept(&self) -> &'static str {
+        "application/vnd.github.v3+json"
+    }
+
+    fn pull_requests(&self, body: Value) -> serde_json::Result<Vec<PullRequest>> {
+        let data: SearchResponse = serde_json::from_value(body)?;
+        Ok(data
+            .items
+            .into_iter()
+            .map(|item| {
+                let status = get_status(&item);
+                let repo = extract_repo_name(&item.repository_url);
+                let repo_url = format!("https://github.com/{}", repo);
+                PullRequest {
+                    title: item.title,
+                    url: item.html_url,
+                    repo,
+                    repo_url,
+                    status,
+                    date: format_date(&item.created_at),
+                    created_at: item.created_at,
+                    updated_at: item.updated_at,
+                    comments: item.comments,
+                }
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+use serde::Deserialize;
+use serde_json::Value;
+use super::{format_date, ContributionProvider, PullRequest};
+
+/// Merge requests of one project on gitlab.com or a self-hosted GitLab.
+pub struct GitLab {
+    /// Web address of the instance, without a trailing slash.
+    pub site: &'static str,
+}
+
+#[derive(Deserialize)]
+struct MergeRequest {
+    title: String,
+    web_url: String,
+    state: String,
+    created_at: String,
+    updated_at: String,
+    user_notes_count: u32,
+}
+
+impl ContributionProvider for GitLab {
+    fn pull_requests(&self, body: Value) -> serde_json::Result<Vec<PullRequest>> {
+        let merge_requests: Vec<MergeRequest> = serde_json::from_value(body)?;
+        Ok(merge_requests
+            .into_iter()
+            .map(|mr| {
+                // `{site}/{group}/{project}/-/merge_requests/{iid}`; groups nest.
+                let path = mr.web_url.strip_prefix(self.site).unwrap_or(&mr.web_url).trim_start_matches('/');
+                let repo = path.split("/-/").next().unwrap_or(path).to_string();
+                let status = match mr.state.as_str() {
+                    "opened" | "locked" => "open",
+                    "merged" => "merged",
+                    _ => "closed",
+                };
+                PullRequest {
+                    title: mr.title,
+                    repo_url: format!("{}/{}", self.site, repo),
+                    repo,
+                    url: mr.web_url,
+                    status: status.to_string(),
+                    date: format_date(&mr.created_at),
+                    created_at: mr.created_at,
+                    updated_at: mr.updated_at,
+                    comments: mr.user_notes_count,
+                }
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn merge_request(web_url: &str, state: &str) -> Value {
+        json!({
+            "title": "Fix",
+            "web_url": web_url,
+            "state": state,
+            "created_at": "2024-03-01T12:00:00.000Z",
+            "updated_at": "2024-03-02T12:00:00.000Z",
+            "user_notes_count": 3,
+        })
+    }
+
+    #[test]
+    fn maps_states() {
+        let gitlab = GitLab { site: "https://gitlab.com" };
+        let states = ["opened", "locked", "merged", "closed"];
+        let body = Value::Array(
+            states.iter().map(|state| merge_request("https://gitlab.com/a/b/-/merge_requests/1", state)).collect(),
+        );
+        let statuses: Vec<_> = gitlab.pull_requests(body).unwrap().into_iter().map(|pr| pr.status).collect();
+        assert_eq!(statuses, ["open", "open", "merged", "closed"]);
+    }
+
+    #[test]
+    fn reads_project_paths_of_subgroups() {
+        let gitlab = GitLab { site: "https://git.example.com/gitlab" };
+        let url = "https://git.example.com/gitlab/group/sub/project/-/merge_requests/7";
+        let pr = gitlab.pull_requests(json!([merge_request(url, "merged")])).unwrap().remove(0);
+        assert_eq!(pr.repo, "group/sub/project");
+        assert_eq!(pr.repo_url, "https://git.example.com/gitlab/group/sub/project");
+        assert_eq!(pr.url, url);
+        assert_eq!(pr.comments, 3);
+        assert_eq!(pr.date, "Mar 1, 2024");
+    }
+}
@@ -0,0 +1,204 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+//! Forges the contributions section lists pull requests from, each reading
+//! its own API's responses into [`PullRequest`]s.
+
+mod gitea;
+mod github;
+mod gitlab;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::cmp::Reverse;
+use crate::data::{ContributionSearch, ContributionSource};
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct PullRequest {
+    pub title: String,
+    pub url: String,
+    pub repo: String,
+    pub repo_url: String,
+    /// `open`, `merged` or `closed`.
+    pub status: String,
+    /// Display date of `created_at`.
+    pub date: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub comments: u32,
+}
+
+pub trait ContributionProvider {
+    /// `Accept` header of the requests.
+    fn accept(&self) -> &'static str {
+        "application/json"
+    }
+
+    /// Pull requests in the body of a response to one of the provider's
+    /// requests.
+    fn pull_requests(&self, body: Value) -> serde_json::Result<Vec<PullRequest>>;
+}
+
+/// Reader of the responses to `source`, or `None` for a provider this build
+/// does not know.
+pub fn provider(source: &ContributionSource) -> Option<Box<dyn ContributionProvider>> {
+    match source.provider {
+        "github" => Some(Box::new(github::GitHub)),
+        "gitlab" => Some(Box::new(gitlab::GitLab { site: source.site })),
+        "gitea" => Some(Box::new(gitea::Gitea { site: source.site })),
+        _ => None,
+    }
+}
+
+/// Pull requests in one of `search.states`, ordered by `search.sort` with
+/// the newest or most discussed first, at most `search.count` of them.
+pub fn merge(mut items: Vec<PullRequest>, search: &ContributionSearch) -> Vec<PullRequest> {
+    items.retain(|pr| search.states.contains(&pr.status.as_str()));
+    match search.sort {
+        "updated" => items.sort_by_cached_key(|pr| Reverse(timestamp(&pr.updated_at))),
+        "comments" => items.sort_by_cached_key(|pr| Reverse((pr.comments, timestamp(&pr.created_at)))),
+        _ => items.sort_by_cached_key(|pr| Reverse(timestamp(&pr.created_at))),
+    }
+    items.truncate(search.count as usize);
+    items
+}
+
+fn format_date(iso_date: &str) -> String {
+    let date_part = iso_date.split('T').next().unwrap_or(iso_date);
+    let parts: Vec<&str> = date_part.split('-').collect();
+    if parts.len() == 3 {
+        let month = match parts[1] {
+            "01" => "Jan",
+            "02" => "Feb",
+            "03" => "Mar",
+            "04" => "Apr",
+            "05" => "May",
+            "06" => "Jun",
+            "07" => "Jul",
+            "08" => "Aug",
+            "09" => "Sep",
+            "10" => "Oct",
+            "11" => "Nov",
+            "12" => "Dec",
+            _ => parts[1],
+        };
+        let day = parts[2].trim_start_matches('0');
+        format!("{} {}, {}", month, day, parts[0])
+    } else {
+        date_part.to_string()
+    }
+}
+
+/// Seconds since 1970 of an RFC 3339 time, 0 when malformed. Forges report
+/// times in different zones, so their strings do not sort by themselves.
+fn timestamp(time: &str) -> i64 {
+    let number = |start: usize, end: usize| time.get(start..end).and_then(|part| part.parse::<i64>().ok());
+    let (Some(year), Some(month), Some(day), Some(hour), Some(minute), Some(second)) =
+        (number(0, 4), number(5, 7), number(8, 10), number(11, 13), number(14, 16), number(17, 19))
+    else {
+        return 0;
+    };
+
+    // Days since 1970 of the proleptic Gregorian date, with years starting
+    // in March so leap days come last.
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+
+    let zone = time[19..].trim_start_matches(|c: char| c == '.' || c.is_ascii_digit());
+    let zone = zone.strip_prefix('+').map(|offset| (1, offset)).or(zone.strip_prefix('-').map(|offset| (-1, offset)));
+    // `±HH:MM`, or `±HHMM` as some Gitea and Forgejo instances send.
+    let offset = zone.map_or(0, |(sign, offset)| {
+        let offset = offset.replacen(':', "", 1);
+        let part = |start: usize, end: usize| offset.get(start..end).and_then(|part| part.parse::<i64>().ok());
+        sign * (part(0, 2).unwrap_or(0) * 3600 + part(2, 4).unwrap_or(0) * 60)
+    });
+
+    days * 86_400 + hour * 3600 + minute * 60 + second - offset
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pr(title: &str, status: &str, created_at: &str, comments: u32) -> PullRequest {
+        PullRequest {
+            title: title.to_string(),
+            url: String::new(),
+            repo: String::new(),
+            repo_url: String::new(),
+            status: status.to_string(),
+            date: format_date(created_at),
+            created_at: created_at.to_string(),
+            updated_at: created_at.to_string(),
+            comments,
+        }
+    }
+
+    fn search(sort: &'static str, count: u32) -> ContributionSearch {
+        ContributionSearch { sources: &[], states: &["open", "merged"], count, sort }
+    }
+
+    fn titles(items: &[PullRequest]) -> Vec<&str> {
+        items.iter().map(|pr| pr.title.as_str()).collect()
+    }
+
+    #[test]
+    fn converts_times_to_unix_seconds() {
+        assert_eq!(timestamp("1970-01-01T00:00:00Z"), 0);
+        assert_eq!(timestamp("2024-03-01T12:00:00Z"), 1_709_294_400);
+        assert_eq!(timestamp("2024-02-29T23:59:59Z"), 1_709_251_199);
+        assert_eq!(timestamp("1969-12-31T23:59:59Z"), -1);
+    }
+
+    #[test]
+    fn applies_zone_offsets_and_ignores_fractions() {
+        assert_eq!(timestamp("2024-03-01T14:00:00+02:00"), 1_709_294_400);
+        assert_eq!(timestamp("2024-03-01T10:30:00-01:30"), 1_709_294_400);
+        assert_eq!(timestamp("2024-03-01T12:00:00.123Z"), 1_709_294_400);
+        assert_eq!(timestamp("2024-03-01T14:00:00.5+02:00"), 1_709_294_400);
+        assert_eq!(timestamp("2024-03-01T17:30:00+0530"), 1_709_294_400);
+        assert_eq!(timestamp("2024-03-01T10:30:00.25-0130"), 1_709_294_400);
+    }
+
+    #[test]
+    fn treats_malformed_times_as_zero() {
+        assert_eq!(timestamp(""), 0);
+        assert_eq!(timestamp("2024-03-01"), 0);
+        assert_eq!(timestamp("yesterday at noon"), 0);
+        assert_eq!(timestamp("2024-03-01T12:00:0Ж"), 0);
+    }
+
+    #[test]
+    fn merges_by_instant_across_zones() {
+        let items = vec![
+            pr("gitea", "open", "2024-03-01T13:30:00+02:00", 0),
+            pr("github", "merged", "2024-03-01T11:45:00Z", 0),
+            pr("gitlab", "open", "2024-03-01T12:00:00.000Z", 0),
+        ];
+        assert_eq!(titles(&merge(items, &search("created", 5))), ["gitlab", "github", "gitea"]);
+    }
+
+    #[test]
+    fn filters_states_and_truncates() {
+        let items = vec![
+            pr("closed", "closed", "2024-03-04T00:00:00Z", 0),
+            pr("old", "open", "2024-03-01T00:00:00Z", 0),
+            pr("new", "merged", "2024-03-03T00:00:00Z", 0),
+            pr("middle", "open", "2024-03-02T00:00:00Z", 0),
+        ];
+        assert_eq!(titles(&merge(items, &search("created", 2))), ["new", "middle"]);
+    }
+
+    #[test]
+    fn sorts_by_comments_then_newest() {
+        let items = vec![
+            pr("quiet", "open", "2024-03-03T00:00:00Z", 1),
+            pr("older", "open", "2024-03-01T00:00:00Z", 4),
+            pr("newer", "open", "2024-03-02T00:00:00Z", 4),
+        ];
+        assert_eq!(titles(&merge(items, &search("comments", 5))), ["newer", "older", "quiet"]);
+    }
+}
@@ -0,0 +1,56 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+use leptos::prelude::*;
+use crate::data::{Resume, RESUMES};
+
+/// Every edition the app can show, provided once by `App`.
+#[derive(Clone, Copy)]
+struct Resumes(RwSignal<&'static [Resume]>);
+
+/// Starts from the compiled-in `RESUMES`. With the `runtime-data` feature
+/// they are replaced by `resumes.json` once it loads, so content changes
+/// reach the page without rebuilding the wasm.
+pub fn provide_resumes() {
+    let resumes = RwSignal::new(RESUMES);
+
+    #[cfg(feature = "runtime-data")]
+    Effect::new(move || {
+        leptos::task::spawn_local(async move {
+            match load().await {
+                Ok(loaded) => resumes.set(loaded),
+                Err(e) => leptos::logging::warn!("{}: {}", DATA_PATH, e),
+            }
+        });
+    });
+
+    provide_context(Resumes(resumes));
+}
+
+pub fn use_resumes() -> ReadSignal<&'static [Resume]> {
+    expect_context::<Resumes>().0.read_only()
+}
+
+/// Written by `resume site-data`, which Trunk runs before every build.
+#[cfg(feature = "runtime-data")]
+const DATA_PATH: &str = "resumes.json";
+
+/// Loaded once per page, so leaking it is bounded.
+#[cfg(feature = "runtime-data")]
+async fn load() -> Result<&'static [Resume], String> {
+    use gloo_net::http::Request;
+    use web_sys::RequestCache;
+
+    let response = Request::get(DATA_PATH)
+        .cache(RequestCache::NoCache)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    if !response.ok() {
+        return Err(format!("HTTP {}", response.status()));
+    }
+    let resumes: Vec<Resume> = response.json().await.map_err(|e| e.to_string())?;
+    if resumes.is_empty() {
+        return Err("no resumes".to_string());
+    }
+    Ok(resumes.leak())
+}
@@ -0,0 +1,81 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+use leptos::prelude::*;
+use gloo_storage::{LocalStorage, Storage};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use crate::hydration::{restored, PRERENDER};
+
+const STORAGE_KEY: &str = "theme";
+const DARK_QUERY: &str = "(prefers-color-scheme: dark)";
+
+/// Colour mode picked in the theme switcher.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ThemeMode {
+    Light,
+    Dark,
+    System,
+}
+
+impl ThemeMode {
+    pub const ALL: [ThemeMode; 3] = [ThemeMode::Light, ThemeMode::Dark, ThemeMode::System];
+
+    /// Stored value, also read by the inline script in `index.html`.
+    fn key(self) -> &'static str {
+        match self {
+            ThemeMode::Light => "light",
+            ThemeMode::Dark => "dark",
+            ThemeMode::System => "system",
+        }
+    }
+}
+
+/// Selected mode, provided once by `App`.
+#[derive(Clone, Copy)]
+struct Theme(RwSignal<ThemeMode>);
+
+/// Restores the stored mode, following the system by default, and keeps
+/// `LocalStorage` and `<html data-theme>` in sync with it and, in system
+/// mode, with `prefers-color-scheme` changes. The page is pre-rendered in
+/// system mode.
+pub fn provide_theme() {
+    let mode = restored(ThemeMode::System, stored_mode);
+    let system_dark = RwSignal::new(true);
+
+    let query = if PRERENDER { None } else { window().match_media(DARK_QUERY).ok().flatten() };
+    if let Some(query) = query {
+        system_dark.set(query.matches());
+        let changed = query.clone();
+        let on_change = Closure::<dyn Fn()>::new(move || system_dark.set(changed.matches()));
+        let _ = query.add_event_listener_with_callback("change", on_change.as_ref().unchecked_ref());
+        // Listens for the lifetime of the page.
+        on_change.forget();
+    }
+
+    Effect::new(move || {
+        let mode = mode.get();
+        let _ = LocalStorage::set(STORAGE_KEY, mode.key());
+        let dark = match mode {
+            ThemeMode::Light => false,
+            ThemeMode::Dark => true,
+            ThemeMode::System => system_dark.get(),
+        };
+        if let Some(root) = document().document_element() {
+            let _ = root.set_attribute("data-theme", if dark { "dark" } else { "light" });
+        }
+    });
+
+    provide_context(Theme(mode));
+}
+
+pub fn use_theme_mode() -> RwSignal<ThemeMode> {
+    expect_context::<Theme>().0
+}
+
+fn stored_mode() -> ThemeMode {
+    let stored: Option<String> = LocalStorage::get(STORAGE_KEY).ok();
+    ThemeMode::ALL
+        .into_iter()
+        .find(|mode| stored.as_deref() == Some(mode.key()))
+        .unwrap_or(ThemeMode::System)
+}
@@ -0,0 +1,42 @@
+// Copyright (c) 2025 Mykyta Voronyi. Licensed under MIT.
+
+use leptos::prelude::*;
+use web_sys::UrlSearchParams;
+use crate::data::{Resume, DEFAULT_VARIANT};
+use crate::hydration::restored;
+use crate::resumes::use_resumes;
+
+const QUERY_PARAM: &str = "variant";
+
+/// Variant requested through `?variant=`, fixed for the lifetime of the page
+/// once the pre-rendered complete resume is hydrated.
+#[derive(Clone, Copy)]
+struct Variant(RwSignal<&'static str>);
+
+/// Needs the resumes context, so `App` provides that first.
+pub fn provide_variant() {
+    let resumes = use_resumes();
+    let variant = restored(DEFAULT_VARIANT, move || requested_variant(resumes.get_untracked()));
+
+    // Variants only `resumes.json` has are known once it loads.
+    #[cfg(feature = "runtime-data")]
+    Effect::new(move || variant.set(requested_variant(resumes.get())));
+
+    provide_context(Variant(variant));
+}
+
+pub fn use_variant() -> ReadSignal<&'static str> {
+    expect_context::<Variant>().0.read_only()
+}
+
+/// Unknown or missing ids fall back to the complete resume.
+fn requested_variant(resumes: &'static [Resume]) -> &'static str {
+    let search = window().location().search().unwrap_or_default();
+    let requested = UrlSearchParams::new_with_str(&search)
+        .ok()
+        .and_then(|params| params.get(QUERY_PARAM));
+
+    requested
+        .and_then(|id| resumes.iter().map(|r| r.variant).find(|&v| v == id))
+        .unwrap_or(DEFAULT_VARIANT)
+}